@@ -0,0 +1,110 @@
+//! Measures the "update all 3D sources" step of a frame: moving the listener and 50 emitters,
+//! once through the per-instance setters and once through
+//! `AudioEngine::set_many_instance_positions`.
+//!
+//! This needs FMOD's dev libraries and the banks in `/resources`, just like the example, and
+//! runs headless, so no audio device is needed. Run it with `cargo bench`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use fmod_test_bed::{AudioEngine, EventInstance};
+use glam::Vec2;
+use u64_id::U64Id;
+
+const EMITTER_COUNT: usize = 50;
+const FRAME_COUNT: u32 = 10_000;
+
+fn main() {
+    let mut engine = AudioEngine::new_headless().unwrap();
+    engine
+        .load_bank_files_from_memory(
+            U64Id::new(),
+            &[
+                include_bytes!("../resources/Master.strings.bank"),
+                include_bytes!("../resources/Master.bank"),
+                include_bytes!("../resources/Music.bank"),
+            ],
+        )
+        .unwrap();
+
+    let instances: Vec<EventInstance> = (0..EMITTER_COUNT)
+        .map(|_| {
+            engine
+                .create_event_instance("event:/Music/Level 02")
+                .unwrap()
+        })
+        .collect();
+
+    let per_instance = measure(|frame| {
+        engine
+            .set_listener_position(listener_position(frame))
+            .unwrap();
+        for (index, instance) in instances.iter().enumerate() {
+            let (position, velocity) = emitter_position_velocity(frame, index);
+            instance.set_position_velocity(position, velocity).unwrap();
+        }
+    });
+
+    let batched = measure(|frame| {
+        engine
+            .set_listener_position(listener_position(frame))
+            .unwrap();
+        let updates: Vec<_> = instances
+            .iter()
+            .enumerate()
+            .map(|(index, instance)| {
+                let (position, velocity) = emitter_position_velocity(frame, index);
+                (instance, position, velocity)
+            })
+            .collect();
+        engine.set_many_instance_positions(&updates).unwrap();
+    });
+
+    report("per instance", per_instance);
+    report("set_many_instance_positions", batched);
+    println!(
+        "set_many_instance_positions takes {:.1}% of the per instance time",
+        batched.as_secs_f64() / per_instance.as_secs_f64() * 100.0
+    );
+
+    for instance in instances {
+        instance.mark_for_release().unwrap();
+    }
+}
+
+/// Runs `frame` [`FRAME_COUNT`] times after a warm up, and returns the average time it took.
+fn measure(mut frame: impl FnMut(u32)) -> Duration {
+    for index in 0..FRAME_COUNT / 10 {
+        frame(black_box(index));
+    }
+
+    let start = Instant::now();
+    for index in 0..FRAME_COUNT {
+        frame(black_box(index));
+    }
+
+    start.elapsed() / FRAME_COUNT
+}
+
+fn report(name: &str, per_frame: Duration) {
+    println!(
+        "{:<28} {:>8.2?} per frame, {:>8.2?} per emitter",
+        name,
+        per_frame,
+        per_frame / EMITTER_COUNT as u32
+    );
+}
+
+fn listener_position(frame: u32) -> Vec2 {
+    Vec2::new(frame as f32 * 0.01, 0.0)
+}
+
+fn emitter_position_velocity(frame: u32, index: usize) -> (Vec2, Vec2) {
+    let angle = frame as f32 * 0.01 + index as f32;
+    let position = Vec2::from_angle(angle) * 10.0;
+
+    (position, position.perp() * 0.01)
+}
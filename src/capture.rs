@@ -0,0 +1,182 @@
+//! Capturing of the final mix, so tests can check what FMOD actually outputs.
+//! This is native only, as it goes through libfmod's raw FFI to install a DSP
+//! with our own read callback, which the wasm bindings have no equivalent for.
+
+use std::{
+    collections::VecDeque,
+    ffi::{c_int, c_uint, c_void},
+    ptr, slice,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use color_eyre::eyre::eyre;
+use libfmod::ffi;
+
+use crate::{fmod, AnyResult, CHANNEL_CONTROL_DSP_HEAD};
+
+/// How many samples we hold onto before dropping the oldest ones. This is two
+/// seconds of stereo audio at 48kHz, which is plenty if the capture is read every frame.
+const MAX_BUFFERED_SAMPLES: usize = 48_000 * 2 * 2;
+
+#[derive(Debug, Default)]
+struct CaptureBuffer {
+    samples: VecDeque<f32>,
+    channels: usize,
+}
+
+/// Captures the mixed output of FMOD, as installed with [`AudioEngine::capture_output`](crate::AudioEngine::capture_output).
+///
+/// The capture is removed from the mix when this is dropped.
+#[derive(Debug)]
+pub struct OutputCapture {
+    channel_group: fmod::ChannelGroup,
+    dsp: fmod::Dsp,
+    buffer: Arc<Mutex<CaptureBuffer>>,
+    /// The strong count of `buffer` which FMOD holds as the DSP's userdata.
+    userdata: *const Mutex<CaptureBuffer>,
+}
+
+impl OutputCapture {
+    /// Installs a capture DSP at the head of the master channel group, so it sees the final mix.
+    pub(crate) fn new(system: &fmod::System) -> AnyResult<Self> {
+        let buffer = Arc::new(Mutex::new(CaptureBuffer::default()));
+
+        // SAFETY: FMOD expects unused fields of the description to be zeroed.
+        let mut description: ffi::FMOD_DSP_DESCRIPTION = unsafe { std::mem::zeroed() };
+        description.pluginsdkversion = ffi::FMOD_PLUGIN_SDK_VERSION;
+        description.numinputbuffers = 1;
+        description.numoutputbuffers = 1;
+        description.read = Some(capture_read);
+        // this strong count is given back in `Drop`
+        let userdata = Arc::into_raw(buffer.clone());
+        description.userdata = userdata as *mut c_void;
+
+        let mut dsp = ptr::null_mut();
+        // SAFETY: the description outlives the call, and FMOD copies what it needs out of it.
+        let result =
+            unsafe { ffi::FMOD_System_CreateDSP(system.as_mut_ptr(), &description, &mut dsp) };
+        if result != ffi::FMOD_OK {
+            // SAFETY: FMOD never got the userdata, so we still own it.
+            unsafe { Arc::from_raw(userdata) };
+            return Err(eyre!("FMOD_System_CreateDSP failed with code {}", result));
+        }
+
+        let dsp = fmod::Dsp::from(dsp);
+        let channel_group = system.get_master_channel_group()?;
+        channel_group.add_dsp(CHANNEL_CONTROL_DSP_HEAD, dsp)?;
+
+        Ok(Self {
+            channel_group,
+            dsp,
+            buffer,
+            userdata,
+        })
+    }
+
+    /// Reads the oldest captured samples into `out`, returning how many were written. Samples are
+    /// interleaved, with [`OutputCapture::channels`] samples per frame.
+    ///
+    /// Samples are captured as FMOD mixes them, so call this regularly; only the latest two seconds
+    /// or so are kept around.
+    pub fn read(&self, out: &mut [f32]) -> AnyResult<usize> {
+        let mut buffer = self.lock_buffer()?;
+        let count = out.len().min(buffer.samples.len());
+        for (out, sample) in out.iter_mut().zip(buffer.samples.drain(..count)) {
+            *out = sample;
+        }
+
+        Ok(count)
+    }
+
+    /// Returns the number of channels in the captured mix, or `0` if nothing has been mixed yet.
+    pub fn channels(&self) -> AnyResult<usize> {
+        Ok(self.lock_buffer()?.channels)
+    }
+
+    fn lock_buffer(&self) -> AnyResult<MutexGuard<'_, CaptureBuffer>> {
+        self.buffer
+            .lock()
+            .map_err(|_| eyre!("the capture buffer was poisoned by a panic"))
+    }
+}
+
+impl Drop for OutputCapture {
+    fn drop(&mut self) {
+        // once the dsp is released, FMOD won't call our read callback anymore, so the userdata
+        // can be dropped too. If releasing fails, the mixer thread may still read the userdata,
+        // so it's leaked instead. There's nothing else to do about errors in a drop.
+        self.channel_group.remove_dsp(self.dsp).ok();
+        if self.dsp.release().is_ok() {
+            // SAFETY: this is the strong count given to FMOD in `OutputCapture::new`, and FMOD
+            // no longer holds it.
+            unsafe { Arc::from_raw(self.userdata) };
+        }
+    }
+}
+
+/// FMOD's read callback for our capture DSP, which runs on the mixer thread. Audio passes through
+/// untouched while we keep a copy of it.
+unsafe extern "C" fn capture_read(
+    dsp_state: *mut ffi::FMOD_DSP_STATE,
+    inbuffer: *mut f32,
+    outbuffer: *mut f32,
+    length: c_uint,
+    inchannels: c_int,
+    outchannels: *mut c_int,
+) -> ffi::FMOD_RESULT {
+    // FMOD shouldn't hand us these, but there's no recovering from them on the mixer thread
+    if inbuffer.is_null() || outbuffer.is_null() || outchannels.is_null() || dsp_state.is_null() {
+        return ffi::FMOD_OK;
+    }
+
+    let sample_count = length as usize * inchannels.max(0) as usize;
+    ptr::copy_nonoverlapping(inbuffer, outbuffer, sample_count);
+    *outchannels = inchannels;
+
+    let mut userdata = ptr::null_mut();
+    let result =
+        ffi::FMOD_DSP_GetUserData((*dsp_state).instance as *mut ffi::FMOD_DSP, &mut userdata);
+    if result != ffi::FMOD_OK || userdata.is_null() {
+        return ffi::FMOD_OK;
+    }
+
+    let buffer = &*(userdata as *const Mutex<CaptureBuffer>);
+    if let Ok(mut buffer) = buffer.lock() {
+        buffer.channels = inchannels as usize;
+        buffer
+            .samples
+            .extend(slice::from_raw_parts(inbuffer, sample_count));
+
+        let overflow = buffer.samples.len().saturating_sub(MAX_BUFFERED_SAMPLES);
+        buffer.samples.drain(..overflow);
+    }
+
+    ffi::FMOD_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_BUFFERED_SAMPLES;
+    use crate::AudioEngine;
+
+    #[test]
+    fn read_drains_the_captured_mix() {
+        let engine = AudioEngine::new_headless().unwrap();
+        let capture = engine.capture_output().unwrap();
+        assert_eq!(capture.read(&mut [0.0; 16]).unwrap(), 0);
+
+        engine.update_by(1).unwrap();
+        let channels = capture.channels().unwrap();
+        assert!(channels > 0);
+
+        // nothing is playing, so the mix is silent
+        let mut first = vec![1.0; channels];
+        assert_eq!(capture.read(&mut first).unwrap(), channels);
+        assert!(first.iter().all(|&sample| sample == 0.0));
+
+        let mut rest = vec![0.0; MAX_BUFFERED_SAMPLES];
+        let remaining = capture.read(&mut rest).unwrap();
+        assert_eq!(remaining % channels, 0);
+        assert_eq!(capture.read(&mut rest).unwrap(), 0);
+    }
+}
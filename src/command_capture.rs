@@ -0,0 +1,159 @@
+//! Recording of every studio command to a file, and replaying such a file, which makes audio
+//! bugs reproducible. This is native only, as it goes through libfmod's raw FFI like the output
+//! capture does, and the wasm build has no file system to write to anyway.
+
+use std::{ffi::CString, path::Path, ptr};
+
+use bitflags::bitflags;
+use color_eyre::eyre::eyre;
+use libfmod::ffi;
+
+use crate::{fmod, AnyResult, PlaybackState};
+
+bitflags! {
+    /// Flags for [`AudioEngine::start_command_capture`](crate::AudioEngine::start_command_capture).
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub struct CommandCaptureFlags: u32 {
+        /// Standard behaviour.
+        const NORMAL = 0x00000000;
+        /// Flushes the file after every command, so a crash doesn't lose the end of the capture.
+        /// This is slow.
+        const FILE_FLUSH = 0x00000001;
+        /// Doesn't record the banks and instances which already exist when the capture starts.
+        const SKIP_INITIAL_STATE = 0x00000002;
+    }
+
+    /// Flags for [`AudioEngine::load_command_replay`](crate::AudioEngine::load_command_replay).
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub struct CommandReplayFlags: u32 {
+        /// Standard behaviour.
+        const NORMAL = 0x00000000;
+        /// Keeps whatever the replay created around once it stops.
+        const SKIP_CLEANUP = 0x00000001;
+        /// Plays the commands as fast as possible, rather than at the pace they were recorded.
+        const FAST_FORWARD = 0x00000002;
+        /// Doesn't load the banks named in the capture, for when they're already loaded.
+        const SKIP_BANK_LOAD = 0x00000004;
+    }
+}
+
+/// Starts writing every command sent to `studio` into the file at `path`.
+pub(crate) fn start_command_capture(
+    studio: &fmod::Studio,
+    path: &Path,
+    flags: CommandCaptureFlags,
+) -> AnyResult {
+    let path = path_to_c_string(path)?;
+
+    // SAFETY: the path outlives the call, and FMOD doesn't hold onto it.
+    let result = unsafe {
+        ffi::FMOD_Studio_System_StartCommandCapture(
+            studio.as_mut_ptr(),
+            path.as_ptr(),
+            flags.bits(),
+        )
+    };
+
+    check(result, "FMOD_Studio_System_StartCommandCapture")
+}
+
+/// Stops a capture started with [`start_command_capture`], closing its file.
+pub(crate) fn stop_command_capture(studio: &fmod::Studio) -> AnyResult {
+    // SAFETY: the studio pointer comes from a live studio system.
+    let result = unsafe { ffi::FMOD_Studio_System_StopCommandCapture(studio.as_mut_ptr()) };
+
+    check(result, "FMOD_Studio_System_StopCommandCapture")
+}
+
+/// A command capture loaded for playback, as returned by
+/// [`AudioEngine::load_command_replay`](crate::AudioEngine::load_command_replay). The replay
+/// runs its commands on the engine which loaded it, during [`AudioEngine::update`](crate::AudioEngine::update).
+///
+/// The replay is released when this is dropped.
+#[derive(Debug)]
+pub struct CommandReplay {
+    replay: *mut ffi::FMOD_STUDIO_COMMANDREPLAY,
+}
+
+impl CommandReplay {
+    pub(crate) fn load(
+        studio: &fmod::Studio,
+        path: &Path,
+        flags: CommandReplayFlags,
+    ) -> AnyResult<Self> {
+        let path = path_to_c_string(path)?;
+
+        let mut replay = ptr::null_mut();
+        // SAFETY: the path outlives the call, and FMOD doesn't hold onto it.
+        let result = unsafe {
+            ffi::FMOD_Studio_System_LoadCommandReplay(
+                studio.as_mut_ptr(),
+                path.as_ptr(),
+                flags.bits(),
+                &mut replay,
+            )
+        };
+        check(result, "FMOD_Studio_System_LoadCommandReplay")?;
+
+        Ok(Self { replay })
+    }
+
+    /// Starts playing the replay from the beginning.
+    pub fn play(&self) -> AnyResult {
+        // SAFETY: FMOD validates the replay handle, even after the studio released it.
+        let result = unsafe { ffi::FMOD_Studio_CommandReplay_Start(self.replay) };
+
+        check(result, "FMOD_Studio_CommandReplay_Start")
+    }
+
+    /// Stops the replay.
+    pub fn stop(&self) -> AnyResult {
+        // SAFETY: FMOD validates the replay handle, even after the studio released it.
+        let result = unsafe { ffi::FMOD_Studio_CommandReplay_Stop(self.replay) };
+
+        check(result, "FMOD_Studio_CommandReplay_Stop")
+    }
+
+    /// Gets the playback state of the replay, which is [`PlaybackState::Stopped`] once every
+    /// command has been replayed.
+    pub fn playback_state(&self) -> AnyResult<PlaybackState> {
+        let mut state = ffi::FMOD_STUDIO_PLAYBACK_STOPPED;
+        // SAFETY: FMOD validates the replay handle, even after the studio released it.
+        let result =
+            unsafe { ffi::FMOD_Studio_CommandReplay_GetPlaybackState(self.replay, &mut state) };
+        check(result, "FMOD_Studio_CommandReplay_GetPlaybackState")?;
+
+        match state {
+            ffi::FMOD_STUDIO_PLAYBACK_PLAYING => Ok(PlaybackState::Playing),
+            ffi::FMOD_STUDIO_PLAYBACK_SUSTAINING => Ok(PlaybackState::Sustaining),
+            ffi::FMOD_STUDIO_PLAYBACK_STOPPED => Ok(PlaybackState::Stopped),
+            ffi::FMOD_STUDIO_PLAYBACK_STARTING => Ok(PlaybackState::Starting),
+            ffi::FMOD_STUDIO_PLAYBACK_STOPPING => Ok(PlaybackState::Stopping),
+            state => Err(eyre!("unknown command replay playback state {}", state)),
+        }
+    }
+}
+
+impl Drop for CommandReplay {
+    fn drop(&mut self) {
+        // SAFETY: FMOD validates the replay handle, so this is fine even if the studio was
+        // released first. There's nothing to do about errors in a drop.
+        unsafe { ffi::FMOD_Studio_CommandReplay_Release(self.replay) };
+    }
+}
+
+fn path_to_c_string(path: &Path) -> AnyResult<CString> {
+    let path = path
+        .to_str()
+        .ok_or_else(|| eyre!("FMOD only takes UTF-8 paths, not {}", path.display()))?;
+
+    Ok(CString::new(path)?)
+}
+
+fn check(result: ffi::FMOD_RESULT, function: &str) -> AnyResult {
+    if result == ffi::FMOD_OK {
+        Ok(())
+    } else {
+        Err(eyre!("{} failed with code {}", function, result))
+    }
+}
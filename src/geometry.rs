@@ -0,0 +1,106 @@
+//! Level geometry for the core system, so FMOD occludes sounds behind walls by itself instead
+//! of occlusion being driven by hand. This is native only, as adding polygons goes through
+//! libfmod's raw FFI, which the wasm bindings have no equivalent for.
+
+use std::ffi::c_int;
+
+use color_eyre::eyre::eyre;
+use glam::Vec3;
+use libfmod::ffi;
+
+use crate::{fmod, AnyResult, CoordinateMapping};
+
+/// A set of polygons which occlude sound, as created with
+/// [`AudioEngine::create_geometry`](crate::AudioEngine::create_geometry).
+///
+/// The geometry stays in the world until [`Geometry::release`] is called.
+#[derive(Debug)]
+pub struct Geometry(pub(crate) fmod::Geometry, pub(crate) CoordinateMapping);
+
+impl Geometry {
+    /// Gives access to the inner [`fmod::Geometry`].
+    pub fn inner(&self) -> &fmod::Geometry {
+        &self.0
+    }
+
+    /// Adds a polygon out of `vertices`, which need to be at least 3, all on one plane, and
+    /// convex, and returns its index. The vertices are in the same coordinates as the listener,
+    /// see [`AudioEngineConfig::coordinate_mapping`](crate::AudioEngineConfig::coordinate_mapping).
+    ///
+    /// `direct_occlusion` muffles sounds behind the polygon, while `reverb_occlusion` reduces how
+    /// much of them reaches reverb. Both go from `0.0`, not occluded, to `1.0`, fully occluded.
+    /// A polygon which isn't `double_sided` only occludes sounds coming from one side of it,
+    /// which FMOD picks by the winding order of its vertices.
+    ///
+    /// ## Panics
+    ///
+    /// In `debug`, we panic if either occlusion is outside of `[0.0, 1.0]`.
+    pub fn add_polygon(
+        &self,
+        direct_occlusion: f32,
+        reverb_occlusion: f32,
+        double_sided: bool,
+        vertices: &[Vec3],
+    ) -> AnyResult<i32> {
+        debug_assert!(
+            (0.0..=1.0).contains(&direct_occlusion) && (0.0..=1.0).contains(&reverb_occlusion),
+            "occlusion must be within [0, 1], got {} and {}",
+            direct_occlusion,
+            reverb_occlusion
+        );
+
+        let vertices: Vec<ffi::FMOD_VECTOR> = vertices
+            .iter()
+            .map(|vertex| {
+                let vertex = self.1.map_to_fmod(*vertex);
+                ffi::FMOD_VECTOR {
+                    x: vertex.x,
+                    y: vertex.y,
+                    z: vertex.z,
+                }
+            })
+            .collect();
+
+        let mut polygon_index: c_int = 0;
+        // SAFETY: FMOD copies the vertices, which outlive the call.
+        let result = unsafe {
+            ffi::FMOD_Geometry_AddPolygon(
+                self.0.as_mut_ptr(),
+                direct_occlusion,
+                reverb_occlusion,
+                double_sided as ffi::FMOD_BOOL,
+                vertices.len() as c_int,
+                vertices.as_ptr(),
+                &mut polygon_index,
+            )
+        };
+        if result != ffi::FMOD_OK {
+            return Err(eyre!(
+                "FMOD_Geometry_AddPolygon failed with code {}",
+                result
+            ));
+        }
+
+        Ok(polygon_index)
+    }
+
+    /// Sets whether the geometry occludes sounds. Inactive geometry is kept around, so
+    /// toggling this is cheaper than releasing and rebuilding it, such as for a door.
+    pub fn set_active(&self, active: bool) -> AnyResult {
+        self.0.set_active(active)?;
+
+        Ok(())
+    }
+
+    /// Checks whether the geometry occludes sounds. See [`Geometry::set_active`].
+    pub fn is_active(&self) -> AnyResult<bool> {
+        Ok(self.0.get_active()?)
+    }
+
+    /// Removes the geometry from the world and frees it.
+    pub fn release(&self) -> AnyResult {
+        self.0.release()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! A handle for driving an [`AudioEngine`](crate::AudioEngine) from other threads. FMOD Studio
+//! and the engine stay on a single thread, and the handle only queues up commands, which the
+//! engine runs in [`AudioEngine::update`](crate::AudioEngine::update).
+
+use std::sync::mpsc::Sender;
+
+use color_eyre::eyre::eyre;
+use glam::Vec2;
+
+use crate::{AnyResult, AudioPositionVelocity, StopMode};
+
+/// A command sent by an [`AudioEngineHandle`], waiting for the engine to run it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AudioCommand {
+    PlayEvent(String),
+    SetListenerPosition(Vec2),
+    SetListenerPositionVelocity(AudioPositionVelocity),
+    SetGlobalParameter(String, f32),
+    SetGlobalPause(bool),
+    StopAll(StopMode),
+}
+
+/// A cloneable, [`Send`] handle to an [`AudioEngine`](crate::AudioEngine), as returned by
+/// [`AudioEngine::engine_handle`](crate::AudioEngine::engine_handle), so gameplay code on any
+/// thread can drive the audio thread which owns the engine.
+///
+/// Every method queues a command and returns right away. The commands run in the order they
+/// were sent, the next time the audio thread calls
+/// [`AudioEngine::update`](crate::AudioEngine::update), and errors from running them are
+/// returned there rather than here. The methods only fail once the
+/// engine has been dropped.
+#[derive(Debug, Clone)]
+pub struct AudioEngineHandle {
+    pub(crate) sender: Sender<AudioCommand>,
+}
+
+impl AudioEngineHandle {
+    /// Queues up [`AudioEngine::play_event`](crate::AudioEngine::play_event). The instance
+    /// can't be handed back across threads, so this is fire and forget.
+    pub fn play_event(&self, event_name: impl Into<String>) -> AnyResult {
+        self.send(AudioCommand::PlayEvent(event_name.into()))
+    }
+
+    /// Queues up [`AudioEngine::set_listener_position`](crate::AudioEngine::set_listener_position).
+    pub fn set_listener_position(&self, position: Vec2) -> AnyResult {
+        self.send(AudioCommand::SetListenerPosition(position))
+    }
+
+    /// Queues up
+    /// [`AudioEngine::set_listener_position_velocity`](crate::AudioEngine::set_listener_position_velocity).
+    pub fn set_listener_position_velocity(&self, attributes: AudioPositionVelocity) -> AnyResult {
+        self.send(AudioCommand::SetListenerPositionVelocity(attributes))
+    }
+
+    /// Queues up [`AudioEngine::set_global_parameter`](crate::AudioEngine::set_global_parameter).
+    pub fn set_global_parameter(&self, parameter_name: impl Into<String>, value: f32) -> AnyResult {
+        self.send(AudioCommand::SetGlobalParameter(
+            parameter_name.into(),
+            value,
+        ))
+    }
+
+    /// Queues up [`AudioEngine::set_global_pause`](crate::AudioEngine::set_global_pause).
+    pub fn set_global_pause(&self, paused: bool) -> AnyResult {
+        self.send(AudioCommand::SetGlobalPause(paused))
+    }
+
+    /// Queues up [`AudioEngine::stop_all`](crate::AudioEngine::stop_all).
+    pub fn stop_all(&self, mode: StopMode) -> AnyResult {
+        self.send(AudioCommand::StopAll(mode))
+    }
+
+    fn send(&self, command: AudioCommand) -> AnyResult {
+        self.sender
+            .send(command)
+            .map_err(|_| eyre!("the audio engine this handle belongs to was dropped"))
+    }
+}
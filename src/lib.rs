@@ -1,9 +1,49 @@
-use glam::Vec2;
+use std::{
+    cell::{Cell, RefCell},
+    fmt::{Display, Formatter},
+    io::Write,
+    ops::Deref,
+    str::FromStr,
+    sync::mpsc::{self, Receiver, Sender},
+};
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    task::{Poll, Waker},
+};
+
+use color_eyre::eyre::{eyre, WrapErr};
+use glam::{Mat3, Vec2, Vec3};
 use u64_id::U64Id;
 
 #[cfg(target_arch = "wasm32")]
 pub mod wasmfmod;
 
+mod reverb;
+pub use reverb::{Environment, ReverbProperties};
+
+mod sound;
+pub use sound::{Channel, Sound, SoundMode};
+
+mod handle;
+use handle::AudioCommand;
+pub use handle::AudioEngineHandle;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod capture;
+#[cfg(not(target_arch = "wasm32"))]
+pub use capture::OutputCapture;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod geometry;
+#[cfg(not(target_arch = "wasm32"))]
+pub use geometry::Geometry;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod command_capture;
+#[cfg(not(target_arch = "wasm32"))]
+pub use command_capture::{CommandCaptureFlags, CommandReplay, CommandReplayFlags};
+
 // This is the trick to change between libfmod and wasmfmod just with flags
 pub mod fmod {
     #[cfg(target_arch = "wasm32")]
@@ -14,64 +54,368 @@ pub mod fmod {
 
 type AnyResult<T = ()> = color_eyre::Result<T>;
 
+/// Index of the DSP at the head of a channel group, which is the one its output comes out of.
+const CHANNEL_CONTROL_DSP_HEAD: i32 = -1;
+
+/// `FMOD_STUDIO_PARAMETER_GLOBAL`, set in the flags of parameters which aren't per instance.
+const PARAMETER_FLAG_GLOBAL: u32 = 0x0000_0004;
+
+/// `FMOD_ERR_EVENT_NOTFOUND`, which FMOD Studio also returns for parameters it can't find.
+const FMOD_ERR_EVENT_NOTFOUND: i32 = 74;
+
+/// The global parameter [`AudioEngine::set_environment`] sets, if the project has one.
+const ENVIRONMENT_PARAMETER: &str = "Environment";
+
+/// `FMOD_DSP_PITCHSHIFT_PITCH`, the index of the pitch ratio parameter of the pitch shifter DSP.
+const DSP_PITCHSHIFT_PITCH: i32 = 0;
+
 #[derive(Debug)]
 pub struct AudioEngine {
     handle: fmod::Studio,
     event_names: Vec<String>,
-    asset_id: Option<U64Id>,
-    listener_position: Vec2,
-    listener_velocity: Vec2,
+    /// The description of each event in `event_names`, at the same index.
+    event_descriptions: Vec<fmod::EventDescription>,
+    banks: Vec<fmod::Bank>,
+    /// Whether a bank in `banks` has finished loading, so `is_ready` doesn't have to ask FMOD.
+    ready: bool,
+    asset_id: Cell<Option<U64Id>>,
+    listener_position: Cell<Vec3>,
+    listener_velocity: Cell<Vec3>,
+    listener_attenuation_position: Cell<Option<Vec3>>,
+    random_state: Cell<u64>,
+    output: OutputMode,
+    track_instances: bool,
+    allow_missing_strings_bank: bool,
+    tracked_instances: RefCell<Vec<fmod::EventInstance>>,
+    position_sources: RefCell<Vec<PositionSource>>,
+    pitch_shifters: RefCell<Vec<PitchShifter>>,
+    coordinate_mapping: CoordinateMapping,
+    global_paused: Cell<bool>,
+    /// Instances created while `global_paused` was set, which get unpaused along with it.
+    globally_paused_instances: RefCell<Vec<fmod::EventInstance>>,
+    bank_callback: RefCell<Option<BankCallback>>,
+    /// Bank events which happened since the last update, handed to `bank_callback` during it.
+    pending_bank_events: RefCell<Vec<BankEvent>>,
+    /// Whether each bank in `banks`, at the same index, had its sample data reported as loaded.
+    bank_samples_reported: RefCell<Vec<bool>>,
+    /// Cloned into every [`AudioEngineHandle`].
+    command_sender: Sender<AudioCommand>,
+    /// Commands from the handles, run at the start of `update`.
+    command_receiver: Receiver<AudioCommand>,
+    /// Futures waiting on the next update, woken at the end of it.
+    #[cfg(feature = "async")]
+    update_wakers: RefCell<Vec<Waker>>,
 }
 
 impl AudioEngine {
     /// Creates a new AudioEngine, initializing FMOD.
+    ///
+    /// If `live_update` is set but FMOD can't open the live update port, such as when a firewall
+    /// blocks it or another game already uses it, initialization fails and an error is returned.
+    /// You can then try again with `live_update` off.
     pub fn new(live_update: bool) -> AnyResult<Self> {
+        Self::new_with_config(AudioEngineConfig {
+            live_update,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a new AudioEngine which doesn't need an audio device, for running in CI and tests.
+    ///
+    /// Nothing is played, and mixing only happens inside [`AudioEngine::update`], so each update
+    /// advances FMOD by the same amount regardless of how much wall-clock time passed.
+    /// See [`OutputMode::NoSoundNrt`].
+    pub fn new_headless() -> AnyResult<Self> {
+        Self::new_with_config(AudioEngineConfig {
+            output: OutputMode::NoSoundNrt,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a new AudioEngine with the given [`AudioEngineConfig`], initializing FMOD.
+    pub fn new_with_config(config: AudioEngineConfig) -> AnyResult<Self> {
         let studio = fmod::Studio::create()?;
 
-        let studio_flags = if live_update {
-            fmod::StudioInit::NORMAL | fmod::StudioInit::LIVEUPDATE
-        } else {
-            fmod::StudioInit::NORMAL
-        };
+        if let Err(err) = initialize_studio(&studio, &config) {
+            // nothing else holds the studio yet, so it would leak otherwise
+            studio.release().ok();
+            return Err(err);
+        }
 
-        studio
-            .initialize(1024, studio_flags, fmod::Init::RIGHTHANDED_3D, None)
-            .expect("Failed to initialize FMOD studio");
+        let (command_sender, command_receiver) = mpsc::channel();
 
         Ok(Self {
             handle: studio,
             event_names: vec![],
-            asset_id: None,
-            listener_position: Vec2::ZERO,
-            listener_velocity: Vec2::ZERO,
+            event_descriptions: vec![],
+            banks: vec![],
+            ready: false,
+            asset_id: Cell::new(None),
+            listener_position: Cell::new(Vec3::ZERO),
+            listener_velocity: Cell::new(Vec3::ZERO),
+            listener_attenuation_position: Cell::new(None),
+            random_state: Cell::new(random_seed()),
+            output: config.output,
+            track_instances: config.track_instances,
+            allow_missing_strings_bank: config.allow_missing_strings_bank,
+            tracked_instances: RefCell::new(vec![]),
+            position_sources: RefCell::new(vec![]),
+            pitch_shifters: RefCell::new(vec![]),
+            coordinate_mapping: config.coordinate_mapping,
+            global_paused: Cell::new(false),
+            globally_paused_instances: RefCell::new(vec![]),
+            bank_callback: RefCell::new(None),
+            pending_bank_events: RefCell::new(vec![]),
+            bank_samples_reported: RefCell::new(vec![]),
+            command_sender,
+            command_receiver,
+            #[cfg(feature = "async")]
+            update_wakers: RefCell::new(vec![]),
         })
     }
 
     /// Loads bank files from memory directly. To get names our correctly in the event list,
     /// make sure to load the .strings file first.
-    pub fn load_bank_files_from_memory(&mut self, asset_id: U64Id, buffers: &[&[u8]]) -> AnyResult {
+    ///
+    /// The returned [`BankLoadReport`] tells what was loaded, which can be used to check that
+    /// event names were resolved.
+    ///
+    /// If any event was loaded without its name and
+    /// [`AudioEngineConfig::allow_missing_strings_bank`] is turned off,
+    /// [`AudioError::StringsBankMissing`] is returned. The banks stay loaded either way.
+    pub fn load_bank_files_from_memory(
+        &mut self,
+        asset_id: U64Id,
+        buffers: &[&[u8]],
+    ) -> AnyResult<BankLoadReport> {
+        self.load_bank_files_from_memory_with_flags(asset_id, buffers, fmod::LoadBank::NORMAL)
+    }
+
+    /// Same as [`AudioEngine::load_bank_files_from_memory`], but with the given [`fmod::LoadBank`]
+    /// flags, such as `DECOMPRESS_SAMPLES` to decompress samples at load time, trading memory
+    /// for CPU.
+    ///
+    /// `UNENCRYPTED` is for loading unencrypted banks when [`AudioEngineConfig::encryption_key`]
+    /// is set. `NONBLOCKING` is ignored, since we read the event list of each bank right after
+    /// loading it, which needs the bank to be fully loaded.
+    ///
+    /// On wasm, the flags are handed to FMOD's HTML5 build as is, so `DECOMPRESS_SAMPLES`
+    /// works the same there. `UNENCRYPTED` has no effect there, as encryption keys can only be
+    /// set on native.
+    pub fn load_bank_files_from_memory_with_flags(
+        &mut self,
+        asset_id: U64Id,
+        buffers: &[&[u8]],
+        flags: fmod::LoadBank,
+    ) -> AnyResult<BankLoadReport> {
+        let flags = flags.difference(fmod::LoadBank::NONBLOCKING);
+        let mut report = BankLoadReport::default();
+
         for buffer in buffers {
-            let bank = self
-                .handle
-                .load_bank_memory(buffer, fmod::LoadBank::NORMAL)?;
-
-            for maybe_name in bank
-                .get_event_list(bank.get_event_count()?)?
-                .into_iter()
-                .flat_map(|v| v.get_path())
-            {
-                self.event_names.push(maybe_name);
+            let bank = self.handle.load_bank_memory(buffer, flags)?;
+            self.add_loaded_bank(bank, &mut report)?;
+        }
+
+        self.asset_id.set(Some(asset_id));
+
+        if report.names_resolved < report.events_added && !self.allow_missing_strings_bank {
+            return Err(AudioError::StringsBankMissing.into());
+        }
+
+        Ok(report)
+    }
+
+    /// Same as [`AudioEngine::load_bank_files_from_memory`], but the banks load in the background,
+    /// and the returned future resolves once they're all loaded. Hand the [`LoadedBanks`] it
+    /// resolves to to [`AudioEngine::add_loaded_banks`] to add their events to the engine.
+    ///
+    /// The future only borrows the engine, so keep calling [`AudioEngine::update`] as usual
+    /// while it's pending. The loading state of the banks is checked again after each update,
+    /// which is when the future gets woken, so it never blocks the calling thread.
+    ///
+    /// The loads are started right away, not on the first poll, so `buffers` doesn't have to
+    /// outlive the future. Sample data may still be loading once the future resolves, just like
+    /// with the blocking version; see [`AudioEngine::flush_sample_loading`].
+    ///
+    /// If the future is dropped before it resolves, the banks keep loading, but their events
+    /// aren't added to this engine until the banks are loaded again. They're still unloaded by
+    /// [`AudioEngine::unload_banks`].
+    ///
+    /// Only available with the `async` feature.
+    #[cfg(feature = "async")]
+    pub fn load_bank_files_async<'a>(
+        &'a self,
+        asset_id: U64Id,
+        buffers: &[&[u8]],
+    ) -> impl Future<Output = AnyResult<LoadedBanks>> + 'a {
+        let banks: AnyResult<Vec<fmod::Bank>> = buffers
+            .iter()
+            .map(|buffer| {
+                Ok(self
+                    .handle
+                    .load_bank_memory(buffer, fmod::LoadBank::NONBLOCKING)?)
+            })
+            .collect();
+
+        async move {
+            let banks = banks?;
+            // `update` does nothing until something is loaded
+            self.asset_id.set(Some(asset_id));
+
+            self.wait_for_updates(|| self.banks_loaded(&banks)).await?;
+
+            Ok(LoadedBanks(banks))
+        }
+    }
+
+    /// Adds the events of banks loaded with [`AudioEngine::load_bank_files_async`], which
+    /// finishes loading them like [`AudioEngine::load_bank_files_from_memory`] does.
+    ///
+    /// Only available with the `async` feature.
+    #[cfg(feature = "async")]
+    pub fn add_loaded_banks(&mut self, banks: LoadedBanks) -> AnyResult<BankLoadReport> {
+        let mut report = BankLoadReport::default();
+        for bank in banks.0 {
+            self.add_loaded_bank(bank, &mut report)?;
+        }
+
+        if report.names_resolved < report.events_added && !self.allow_missing_strings_bank {
+            return Err(AudioError::StringsBankMissing.into());
+        }
+
+        Ok(report)
+    }
+
+    /// Resolves once `is_done` returns `true`, checking it again after each
+    /// [`AudioEngine::update`] rather than spinning in between.
+    #[cfg(feature = "async")]
+    async fn wait_for_updates(&self, mut is_done: impl FnMut() -> AnyResult<bool>) -> AnyResult {
+        std::future::poll_fn(|cx| match is_done() {
+            Ok(false) => {
+                self.update_wakers.borrow_mut().push(cx.waker().clone());
+                Poll::Pending
+            }
+            Ok(true) => Poll::Ready(Ok(())),
+            Err(err) => Poll::Ready(Err(err)),
+        })
+        .await
+    }
+
+    /// Checks whether all of the given banks have finished loading.
+    #[cfg(feature = "async")]
+    fn banks_loaded(&self, banks: &[fmod::Bank]) -> AnyResult<bool> {
+        for bank in banks {
+            match bank.get_loading_state()? {
+                fmod::LoadingState::Loaded => {}
+                fmod::LoadingState::Error => return Err(eyre!("bank failed to load")),
+                _ => return Ok(false),
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Reads the events out of a bank which has finished loading, and keeps track of the bank.
+    fn add_loaded_bank(&mut self, bank: fmod::Bank, report: &mut BankLoadReport) -> AnyResult {
+        for description in bank.get_event_list(bank.get_event_count()?)? {
+            report.events_added += 1;
+
+            if let Ok(name) = description.get_path() {
+                if name.starts_with("event:/") {
+                    report.names_resolved += 1;
+                }
+
+                self.event_names.push(name);
+                self.event_descriptions.push(description);
             }
         }
 
-        self.asset_id = Some(asset_id);
+        self.pending_bank_events
+            .get_mut()
+            .push(BankEvent::Loaded(BankHandle(bank.clone())));
+        self.bank_samples_reported.get_mut().push(false);
+        self.banks.push(bank);
+        // the events can only be read out once the bank is loaded, so this is when it's ready
+        self.ready = true;
+        report.banks_loaded += 1;
 
         Ok(())
     }
 
     /// Unloads the banks from memory, if there are any.
-    pub fn unload_banks(&mut self) {
-        self.handle.unload_all().expect("failed to unload all");
+    ///
+    /// This flushes commands first, as described in [`AudioEngine::flush_commands`], so events
+    /// started just before don't keep the banks in use.
+    pub fn unload_banks(&mut self) -> AnyResult {
+        self.flush_commands()?;
+
+        // events about these banks would hand out invalid handles once they're unloaded
+        let pending_bank_events = self.pending_bank_events.get_mut();
+        pending_bank_events.retain(|event| matches!(event, BankEvent::Unloaded(_)));
+        for bank in &self.banks {
+            if let Ok(id) = bank.get_id() {
+                pending_bank_events.push(BankEvent::Unloaded(id.into()));
+            }
+        }
+
+        self.handle.unload_all()?;
+        self.event_names.clear();
+        self.event_descriptions.clear();
+        self.banks.clear();
+        self.ready = false;
+        self.bank_samples_reported.get_mut().clear();
+
+        Ok(())
+    }
+
+    /// Replaces every loaded bank with the given ones, such as to hot-reload banks rebuilt in
+    /// FMOD Studio while the game runs. The listener and everything else about the engine stay
+    /// as they are, and so does [`AudioEngine::asset_id`], if banks were loaded before.
+    ///
+    /// This is [`AudioEngine::unload_banks`] followed by
+    /// [`AudioEngine::load_bank_files_from_memory`], so the same rules apply: load the strings
+    /// bank first, and every instance of the old banks becomes invalid, as do any
+    /// [`EventDescription`]s and [`BankHandle`]s. Play events again by name to get instances
+    /// from the new banks.
+    pub fn reload_banks(&mut self, buffers: &[&[u8]]) -> AnyResult<BankLoadReport> {
+        let asset_id = self.asset_id.get().unwrap_or_else(U64Id::new);
+        self.unload_banks()?;
+
+        self.load_bank_files_from_memory(asset_id, buffers)
+    }
+
+    /// Blocks until every command sent to FMOD so far has been executed, including any
+    /// asynchronous work they started. FMOD recommends this before unloading banks or shutting
+    /// down, which is why [`AudioEngine::unload_banks`] calls it.
+    pub fn flush_commands(&self) -> AnyResult {
+        self.handle.flush_commands()?;
+
+        Ok(())
+    }
+
+    /// Blocks until all sample data which is currently loading has finished loading. Use this
+    /// when sounds are suddenly needed right away, such as when the player skips a loading
+    /// screen, instead of polling loading states in a loop.
+    ///
+    /// This blocks the calling thread, possibly for a long while if a lot is loading.
+    pub fn flush_sample_loading(&self) -> AnyResult {
+        self.handle.flush_sample_loading()?;
+
+        Ok(())
+    }
+
+    /// Gets every bank loaded with [`AudioEngine::load_bank_files_from_memory`], in the order
+    /// they were loaded, such as to show loading progress.
+    pub fn banks(&self) -> Vec<BankHandle> {
+        self.banks.iter().cloned().map(BankHandle).collect()
+    }
+
+    /// Checks whether at least one bank has finished loading, so events can be played.
+    ///
+    /// Until then, [`AudioEngine::create_event_instance`] and the `play_event` family of methods
+    /// return [`AudioError::NotReady`].
+    pub fn is_ready(&self) -> bool {
+        self.ready
     }
 
     /// Gets all the events loaded in the banks.
@@ -79,9 +423,86 @@ impl AudioEngine {
         &self.event_names
     }
 
+    /// Iterates over all loaded events whose name begins with `prefix`, such as every event
+    /// in the `event:/SFX/Weapons/` folder.
+    pub fn events_with_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.event_names
+            .iter()
+            .map(|v| v.as_str())
+            .filter(move |v| v.starts_with(prefix))
+    }
+
+    /// Checks if an event with the given name was loaded in the banks. This is a cheap check against
+    /// [`AudioEngine::event_names`], so it can be used to validate event names up front.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn has_event(&self, event_name: &(impl AsRef<str> + ?Sized)) -> bool {
+        let event_name = event_name.as_ref();
+
+        self.event_names.iter().any(|v| v == event_name)
+    }
+
+    /// Same as [`AudioEngine::has_event`], but compares the names ignoring ASCII case, since
+    /// FMOD paths are sometimes authored inconsistently.
+    pub fn has_event_ignore_case(&self, event_name: &(impl AsRef<str> + ?Sized)) -> bool {
+        let event_name = event_name.as_ref();
+
+        self.event_names
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(event_name))
+    }
+
+    /// Writes Rust source for an `EventName` enum to `out`, with one variant per loaded event
+    /// and an `AsRef<str>` impl, so it can be passed straight to methods like
+    /// [`AudioEngine::play_event`].
+    ///
+    /// Variant names are derived from the event path, so `event:/Music/Level 02` becomes
+    /// `MusicLevel02`. This is meant to be run once after loading your banks, with the output pasted
+    /// into your own project.
+    pub fn write_event_enum(&self, out: &mut impl Write) -> AnyResult {
+        let mut variants: Vec<(String, &str)> = Vec::with_capacity(self.event_names.len());
+        for event_name in &self.event_names {
+            let base = event_variant_name(event_name);
+            let mut variant = base.clone();
+            let mut suffix = 2;
+            while variants.iter().any(|(v, _)| *v == variant) {
+                variant = format!("{}{}", base, suffix);
+                suffix += 1;
+            }
+
+            variants.push((variant, event_name.as_str()));
+        }
+
+        writeln!(out, "/// Events loaded from FMOD banks.")?;
+        writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+        writeln!(out, "pub enum EventName {{")?;
+        for (variant, event_name) in &variants {
+            writeln!(out, "    /// `{}`", event_name)?;
+            writeln!(out, "    {},", variant)?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        writeln!(out, "impl AsRef<str> for EventName {{")?;
+        writeln!(out, "    fn as_ref(&self) -> &str {{")?;
+        writeln!(out, "        match self {{")?;
+        for (variant, event_name) in &variants {
+            writeln!(
+                out,
+                "            EventName::{} => {:?},",
+                variant, event_name
+            )?;
+        }
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
     /// Returns the asset id that we loaded all our audio from.
     pub fn asset_id(&self) -> Option<U64Id> {
-        self.asset_id
+        self.asset_id.get()
     }
 
     /// Creates a given event instance.
@@ -90,55 +511,212 @@ impl AudioEngine {
     /// You'll need to run [`EventInstance::start`](fmod::EventInstance::start),
     /// and should almost certainly also run [`EventInstance::release`](fmod::EventInstance::release).
     ///
+    /// If no bank has finished loading yet, [`AudioError::NotReady`] is returned. See
+    /// [`AudioEngine::is_ready`].
+    ///
+    /// Names which aren't a path or a GUID return [`AudioError::InvalidEventPath`]
+    /// rather than panicking, so this is safe to call with paths typed in by users, such as in
+    /// an editor.
+    ///
     /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
     /// between the types required.
     pub fn create_event_instance(
         &self,
         event_name: &(impl AsRef<str> + ?Sized),
     ) -> AnyResult<EventInstance> {
-        let event_name = self.event_name_as_ref(event_name);
+        if !self.is_ready() {
+            return Err(AudioError::NotReady.into());
+        }
+
+        let event_name = self.event_name_as_ref(event_name)?;
         let event_descriptor = self.handle.get_event(event_name)?;
 
-        Ok(EventInstance(event_descriptor.create_instance()?))
+        self.instantiate(&event_descriptor)
+    }
+
+    /// Creates an event instance from the event's GUID rather than its name, for projects
+    /// which ship their banks without the strings bank.
+    ///
+    /// Like [`AudioEngine::create_event_instance`], this does *not* start the instance, and
+    /// [`AudioError::NotReady`] is returned if no bank has finished loading yet.
+    pub fn create_event_instance_by_id(&self, id: Guid) -> AnyResult<EventInstance> {
+        if !self.is_ready() {
+            return Err(AudioError::NotReady.into());
+        }
+
+        let event_descriptor = self.handle.get_event_by_id(id.into())?;
+
+        self.instantiate(&event_descriptor)
+    }
+
+    /// Creates an instance of the given event, pausing it if everything is globally paused.
+    fn instantiate(&self, event_descriptor: &fmod::EventDescription) -> AnyResult<EventInstance> {
+        let instance = event_descriptor.create_instance()?;
+        if self.global_paused.get() {
+            instance.set_paused(true)?;
+            self.globally_paused_instances
+                .borrow_mut()
+                .push(instance.clone());
+        }
+
+        Ok(EventInstance(instance, self.coordinate_mapping))
+    }
+
+    /// Gets the description of a given event, which holds everything authored for it in FMOD
+    /// Studio, such as its 3D distances. This doesn't create an instance, so it's cheap to use
+    /// for culling before playing anything.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn event_description(
+        &self,
+        event_name: &(impl AsRef<str> + ?Sized),
+    ) -> AnyResult<EventDescription> {
+        let event_name = self.event_name_as_ref(event_name)?;
+
+        Ok(EventDescription(self.handle.get_event(event_name)?))
+    }
+
+    /// Starts building an instance of a given event, so its pitch, volume, parameters and position
+    /// are all set before it starts. Setting them after [`EventInstance::start`] instead can let
+    /// the event briefly play with its default settings.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn build_event<'a>(
+        &'a self,
+        event_name: &'a (impl AsRef<str> + ?Sized),
+    ) -> EventInstanceBuilder<'a> {
+        EventInstanceBuilder {
+            engine: self,
+            // checked when the event is created in `start`
+            event_name: event_name.as_ref(),
+            pitch: None,
+            volume: None,
+            parameters: vec![],
+            position: None,
+        }
+    }
+
+    /// Starts loading the sample data of a given event, so it starts right away when played,
+    /// rather than stuttering while its samples load. Loading is asynchronous, see
+    /// [`AudioEngine::flush_sample_loading`] to wait for it.
+    ///
+    /// The sample data stays loaded until [`AudioEngine::unload_event_samples`] is called, even
+    /// once no instances are left.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn preload_event(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult {
+        self.event_description(event_name)?.0.load_sample_data()?;
+
+        Ok(())
+    }
+
+    /// Unloads the sample data loaded with [`AudioEngine::preload_event`]. Instances which
+    /// are still playing keep their sample data until they're done.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn unload_event_samples(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult {
+        self.event_description(event_name)?.0.unload_sample_data()?;
+
+        Ok(())
+    }
+
+    /// Estimates how many bytes the sample data of a given event takes up, such as to decide
+    /// which events to keep preloaded under a memory budget.
+    ///
+    /// FMOD doesn't report this per event, so this loads the sample data, waits for it like
+    /// [`AudioEngine::flush_sample_loading`], and measures how much more memory FMOD has
+    /// allocated, before unloading it again. That makes it an approximation: samples shared with
+    /// an event which is already loaded aren't counted, and anything else FMOD allocates in the
+    /// meantime is. This blocks, so measure ahead of time rather than during gameplay.
+    ///
+    /// If the event's sample data is already loaded, this returns an error, as there'd be
+    /// nothing to measure.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn measure_event_samples(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult<u64> {
+        let description = self.event_description(event_name)?.0;
+        if !matches!(
+            description.get_sample_loading_state()?,
+            fmod::LoadingState::Unloaded
+        ) {
+            return Err(eyre!(
+                "the sample data of `{}` is already loaded, so it can't be measured",
+                event_name.as_ref()
+            ));
+        }
+
+        let before = fmod_memory_allocated()?;
+        description.load_sample_data()?;
+        self.handle.flush_sample_loading()?;
+        let after = fmod_memory_allocated()?;
+        description.unload_sample_data()?;
+
+        Ok(after.saturating_sub(before).max(0) as u64)
     }
 
     /// Plays a given event by name. If that event does not exist, an error will be returned.
     ///
     /// ## Starting and Releasing
     ///
-    /// This runs [`EventInstance::start`] and [`EventInstance::mark_for_release`] immediately.
-    /// If you want to avoid running those, use [`AudioEngine::create_event_instance`].
+    /// This runs [`EventInstance::start`] immediately. One-shots are also marked for release, so
+    /// they clean up after themselves once they finish, while looping events are not, so they can
+    /// be stopped and started again. See [`EventDescription::is_oneshot`]. If you want to avoid
+    /// running those, use [`AudioEngine::create_event_instance`].
     ///
     /// ## Event Names
     ///
     /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
     /// between the types required.
     ///
-    /// Event names in FMOD always begin with `event:/`. In `debug`, we will check and panic if any event name provided
-    /// does not begin with this header.
+    /// Event names in FMOD are a path such as `event:/` or `snapshot:/`, or a GUID in braces.
+    /// Anything else returns [`AudioError::InvalidEventPath`].
     pub fn play_event(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult<EventInstance> {
         let event = self.create_event_instance(event_name)?;
-
-        event.start()?;
-        event.mark_for_release()?;
+        self.start_played_event(&event, false)?;
 
         Ok(event)
     }
 
+    /// Plays a given event like [`AudioEngine::play_event`], and reports how it started, such as
+    /// to figure out why an event can't be heard.
+    ///
+    /// FMOD only runs the start during the next update, so this flushes commands like
+    /// [`AudioEngine::flush_commands`] to see the result, which blocks. It's meant for debugging
+    /// rather than for every sound.
+    pub fn play_event_detailed(
+        &self,
+        event_name: &(impl AsRef<str> + ?Sized),
+    ) -> AnyResult<(EventInstance, StartInfo)> {
+        let event = self.play_event(event_name)?;
+        self.flush_commands()?;
+
+        let start_info = StartInfo {
+            was_virtual: event.is_virtual()?,
+            channel_priority: event.property(EventProperty::ChannelPriority)?,
+        };
+
+        Ok((event, start_info))
+    }
+
     /// Plays a given event by name with position data. If that event does not exist, an error will be returned.
     ///
     /// ## Starting and Releasing
     ///
-    /// This runs [`EventInstance::start`] and [`EventInstance::mark_for_release`] immediately.
-    /// If you want to avoid running those, use [`AudioEngine::create_event_instance`].
+    /// This starts the event and releases one-shots like [`AudioEngine::play_event`] does, see
+    /// [Starting and Releasing](AudioEngine::play_event#starting-and-releasing).
     ///
     /// ## Event Names
     ///
     /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
     /// between the types required.
     ///
-    /// Event names in FMOD always begin with `event:/`. In `debug`, we will check and panic if any event name provided
-    /// does not begin with this header.
+    /// Event names in FMOD are a path such as `event:/` or `snapshot:/`, or a GUID in braces.
+    /// Anything else returns [`AudioError::InvalidEventPath`].
     pub fn play_event_with_position(
         &self,
         event_name: &(impl AsRef<str> + ?Sized),
@@ -151,16 +729,16 @@ impl AudioEngine {
     ///
     /// ## Starting and Releasing
     ///
-    /// This runs [`EventInstance::start`] and [`EventInstance::mark_for_release`] immediately.
-    /// If you want to avoid running those, use [`AudioEngine::create_event_instance`].
+    /// This starts the event and releases one-shots like [`AudioEngine::play_event`] does, see
+    /// [Starting and Releasing](AudioEngine::play_event#starting-and-releasing).
     ///
     /// ## Event Names
     ///
     /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
     /// between the types required.
     ///
-    /// Event names in FMOD always begin with `event:/`. In `debug`, we will check and panic if any event name provided
-    /// does not begin with this header.
+    /// Event names in FMOD are a path such as `event:/` or `snapshot:/`, or a GUID in braces.
+    /// Anything else returns [`AudioError::InvalidEventPath`].
     pub fn play_event_with_position_velocity(
         &self,
         event_name: &(impl AsRef<str> + ?Sized),
@@ -170,362 +748,3094 @@ impl AudioEngine {
         let event = self.create_event_instance(event_name)?;
 
         event.set_position_velocity(position, velocity)?;
-        event.start()?;
-        event.mark_for_release()?;
+        self.start_played_event(&event, false)?;
 
         Ok(event)
     }
 
-    /// Sets the master bus to mute. All buses eventually route through the master bus,
-    /// so this will mute the enter game.
-    pub fn set_global_mute(&self, mute: bool) {
-        self.handle
-            .get_bus("bus:/")
-            .unwrap()
-            .set_mute(mute)
-            .unwrap();
-    }
-
-    /// Sets a global parameter. Most parameters are instanced, and for those, you'll need
-    /// to set them *per instance* in [`EventInstance::set_parameter_by_name`]
-    pub fn set_global_parameter(&self, parameter_name: &str, value: f32) -> AnyResult {
-        self.handle
-            .set_parameter_by_name(parameter_name, value, true)?;
-
-        Ok(())
-    }
-
-    /// Checks if any event with the given name is playing at all.
+    /// Fires a one-shot event by name, without handing back the [`EventInstance`]. If that event
+    /// does not exist, an error will be returned.
     ///
-    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
-    /// between the types required.
-    pub fn is_event_playing(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult<bool> {
-        Ok(self.event_instance_count(event_name)? > 0)
-    }
-
-    /// Checks how many times a given event is playing.
+    /// This starts the event and marks it for release immediately, even if it loops, so it's the
+    /// one to use when you don't want to touch the instance afterwards. Holding onto a released instance is a footgun,
+    /// as it becomes invalid as soon as it stops. If you do want the instance, use
+    /// [`AudioEngine::play_event`].
     ///
     /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
     /// between the types required.
-    pub fn event_instance_count(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult<u32> {
-        let event_descriptor = self.handle.get_event(self.event_name_as_ref(event_name))?;
+    pub fn fire_oneshot(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult {
+        let event = self.create_event_instance(event_name)?;
+        self.start_played_event(&event, true)
+    }
 
-        Ok(event_descriptor.get_instance_count()? as u32)
+    /// Fires a one-shot event by name at the given position, without handing back the
+    /// [`EventInstance`]. See [`AudioEngine::fire_oneshot`] for details.
+    pub fn fire_oneshot_at(
+        &self,
+        event_name: &(impl AsRef<str> + ?Sized),
+        position: Vec2,
+    ) -> AnyResult {
+        let event = self.create_event_instance(event_name)?;
+        event.set_position_velocity(position, Vec2::ZERO)?;
+        self.start_played_event(&event, true)
     }
 
-    /// Sets the position of the listener in the spatializer.
+    /// Fires a one-shot event by name at the given position, heard only by the listener at
+    /// `listener_index`, such as a UI sound for one player in split-screen. See
+    /// [`AudioEngine::fire_oneshot`] for details.
     ///
-    /// See [`AudioEngine::set_listener_velocity`] and [`AudioEngine::set_listener_position_velocity`]
-    /// to set the velocity of the listener if that matters for your application. We will continue to use the velocity
-    /// last assigned to this function.
-    pub fn set_listener_position(&mut self, position: Vec2) -> AnyResult {
-        self.set_listener_position_velocity(position, self.listener_velocity)
+    /// If `listener_index` isn't below [`AudioEngine::listener_count`], an error will be
+    /// returned, since FMOD would quietly play the event to nobody.
+    pub fn play_event_for_listener(
+        &self,
+        event_name: &(impl AsRef<str> + ?Sized),
+        position: Vec2,
+        listener_index: i32,
+    ) -> AnyResult {
+        let listener_count = self.listener_count()?;
+        if !(0..listener_count).contains(&listener_index) {
+            return Err(eyre!(
+                "listener index {} is out of range, as there are {} listeners",
+                listener_index,
+                listener_count
+            ));
+        }
+
+        let event = self.create_event_instance(event_name)?;
+        event.set_listener_mask(1 << listener_index)?;
+        event.set_position_velocity(position, Vec2::ZERO)?;
+        self.start_played_event(&event, true)
     }
 
-    /// Sets the velocity of the listener in the spatializer. Some spatializers have
-    /// the doppler effect enabled, so you'll want to set the velocity here too.
+    /// Plays a random event out of all loaded events whose name begins with `prefix`, such as
+    /// `event:/SFX/Footsteps/`. This is useful to add variation to sounds which are played often.
+    /// If no event matches the prefix, an error will be returned.
     ///
-    /// We will use the last let listener position (set either with [`AudioEngine::set_listener_position`]
-    /// or with [`AudioEngine::set_listener_position_velocity`]) as the listener position.
-    pub fn set_listener_velocity(&mut self, velocity: Vec2) -> AnyResult {
-        self.set_listener_position_velocity(self.listener_position, velocity)
+    /// This behaves like [`AudioEngine::play_event`] otherwise. See [`AudioEngine::set_random_seed`]
+    /// to make the choice deterministic.
+    pub fn play_random_event(&self, prefix: &str) -> AnyResult<EventInstance> {
+        let event_names: Vec<&str> = self.events_with_prefix(prefix).collect();
+        if event_names.is_empty() {
+            return Err(eyre!("no loaded event begins with {}", prefix));
+        }
+
+        self.play_random_event_from(&event_names)
     }
 
-    /// Sets the position and velocity of the listener in the spatializer. This is provided to reduce
-    /// the number of FFI calls we do at once, if you're going to set both all the time.
+    /// Plays a random event out of the given event names. If `event_names` is empty, an error
+    /// will be returned.
     ///
-    /// Note: the internally tracked `position` and `velocity` will only be updated when this function
-    /// returns `Ok`.
-    pub fn set_listener_position_velocity(&mut self, position: Vec2, velocity: Vec2) -> AnyResult {
-        self.handle.set_listener_attributes(
-            0,
-            fmod::Attributes3d {
-                position: fmod::Vector::new(position.x, position.y, 0.0),
-                velocity: fmod::Vector::new(velocity.x, velocity.y, 0.0),
-                forward: fmod::Vector::new(0.0, 1.0, 0.0),
-                up: fmod::Vector::new(0.0, 0.0, 1.0),
-            },
-            None,
-        )?;
+    /// This behaves like [`AudioEngine::play_event`] otherwise. See [`AudioEngine::set_random_seed`]
+    /// to make the choice deterministic.
+    pub fn play_random_event_from(&self, event_names: &[&str]) -> AnyResult<EventInstance> {
+        if event_names.is_empty() {
+            return Err(eyre!("no event names were given to pick from"));
+        }
 
-        // update our internals
-        self.listener_position = position;
-        self.listener_velocity = velocity;
+        let index = (self.next_random() % event_names.len() as u64) as usize;
 
-        Ok(())
+        self.play_event(event_names[index])
     }
 
-    /// Gets the internally held listener position. This is the value that was last set using
-    /// [`AudioEngine::set_listener_position`] or [`AudioEngine::set_listener_position_velocity`].
-    ///
-    /// Defaults to [`Vec2::ZERO`].
-    pub fn listener_position(&self) -> Vec2 {
-        self.listener_position
+    /// Seeds the random number generator used by [`AudioEngine::play_random_event`] and
+    /// [`AudioEngine::play_random_event_from`]. By default, it is seeded randomly, so set
+    /// this in tests to get the same events picked every run.
+    pub fn set_random_seed(&self, seed: u64) {
+        self.random_state.set(seed);
     }
 
-    /// Gets the internally held listener velocity. This is the value that was last set using
-    /// [`AudioEngine::set_listener_velocity`] or [`AudioEngine::set_listener_position_velocity`].
-    ///
-    /// Defaults to [`Vec2::ZERO`].
-    pub fn listener_velocity(&self) -> Vec2 {
-        self.listener_velocity
+    /// Gets a bus by path, such as `bus:/` for the master bus or `bus:/Ambience`. If that bus
+    /// does not exist, an error will be returned.
+    pub fn bus(&self, path: &str) -> AnyResult<BusHandle> {
+        Ok(BusHandle(self.handle.get_bus(path)?))
     }
 
-    /// This must be called once per frame. At this point, all commands are *actually* submitted and
-    /// callbacks occur. Basically, the good stuff happens here.
-    ///
-    /// This gets called in [mwe::main_loop] automatically.
-    pub fn update(&self) -> AnyResult {
-        if self.asset_id.is_none() {
-            return Ok(());
-        }
-
-        self.handle.update()?;
+    /// Sets the master bus to mute. All buses eventually route through the master bus,
+    /// so this will mute the enter game.
+    pub fn set_global_mute(&self, mute: bool) -> AnyResult {
+        self.master_bus()?.set_mute(mute)?;
 
         Ok(())
     }
 
-    /// Converts the given event name back into a string, doing our debug check
-    fn event_name_as_ref<'a>(&self, event_name: &'a (impl AsRef<str> + ?Sized)) -> &'a str {
+    /// Sets the volume of the master bus as linear gain, which is what a master volume slider
+    /// wants. All buses eventually route through the master bus, so this scales the whole game.
+    /// See [`BusHandle::set_volume`].
+    pub fn set_master_volume(&self, volume: f32) -> AnyResult {
+        self.master_bus()?.set_volume(volume)?;
+
+        Ok(())
+    }
+
+    /// Gets the volume of the master bus, as set with [`AudioEngine::set_master_volume`].
+    pub fn master_volume(&self) -> AnyResult<f32> {
+        Ok(self.master_bus()?.get_volume()?.0)
+    }
+
+    /// Pauses or unpauses the master bus. All buses eventually route through the master bus,
+    /// so this pauses the entire game without needing to track individual instances.
+    pub fn set_paused(&self, paused: bool) -> AnyResult {
+        self.master_bus()?.set_paused(paused)?;
+
+        Ok(())
+    }
+
+    /// Returns the pause state of the master bus, as set with [`AudioEngine::set_paused`].
+    pub fn is_paused(&self) -> AnyResult<bool> {
+        Ok(self.master_bus()?.get_paused()?)
+    }
+
+    /// Pauses or unpauses everything, such as for a pause menu. This pauses the master bus like
+    /// [`AudioEngine::set_paused`], and on top of that, every instance created while paused starts
+    /// out paused itself. Unpausing unpauses the master bus and all of those instances.
+    ///
+    /// Every instance is attempted, even if an earlier one fails, and the first error is
+    /// returned. Instances which failed to unpause are kept, so calling this again retries them.
+    pub fn set_global_pause(&self, paused: bool) -> AnyResult {
+        self.master_bus()?.set_paused(paused)?;
+        self.global_paused.set(paused);
+
+        let mut first_error = None;
+        if !paused {
+            self.globally_paused_instances
+                .borrow_mut()
+                .retain(|instance| {
+                    // these may have been released and stopped since
+                    if !instance.is_valid() {
+                        return false;
+                    }
+
+                    match instance.set_paused(false) {
+                        Ok(()) => false,
+                        Err(err) => {
+                            first_error.get_or_insert(err.into());
+                            true
+                        }
+                    }
+                });
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Returns whether everything was paused with [`AudioEngine::set_global_pause`].
+    pub fn is_globally_paused(&self) -> bool {
+        self.global_paused.get()
+    }
+
+    /// Sets a global parameter. Most parameters are instanced, and for those, you'll need
+    /// to set them *per instance* in [`EventInstance::set_parameter_by_name`]
+    ///
+    /// If the parameter exists but isn't global, [`AudioError::ParameterNotGlobal`] is returned.
+    pub fn set_global_parameter(&self, parameter_name: &str, value: f32) -> AnyResult {
+        let id = self.global_parameter_id(parameter_name)?;
+
+        self.handle.set_parameter_by_id(id.into(), value, true)?;
+
+        Ok(())
+    }
+
+    /// Sets several global parameters at once, as with [`AudioEngine::set_global_parameter`].
+    ///
+    /// Every parameter is attempted, even if an earlier one fails, and the first error is
+    /// returned.
+    pub fn set_global_parameters(&self, parameters: &[(&str, f32)]) -> AnyResult {
+        let mut first_error = None;
+        for (parameter_name, value) in parameters {
+            if let Err(err) = self.set_global_parameter(parameter_name, *value) {
+                first_error.get_or_insert(err);
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Looks up the id of a global parameter, which skips the name lookup when setting it with
+    /// [`AudioEngine::set_global_parameters_by_id`]. Ids stay the same for as long as the bank
+    /// holding the parameter is loaded, so they're worth looking up once and keeping around.
+    ///
+    /// If the parameter exists but isn't global, [`AudioError::ParameterNotGlobal`] is returned,
+    /// and if no loaded event has it at all, [`AudioError::ParameterNotFound`] is.
+    pub fn global_parameter_id(&self, parameter_name: &str) -> AnyResult<ParameterId> {
+        let description = match self
+            .handle
+            .get_parameter_description_by_name(parameter_name)
+        {
+            Ok(description) => description,
+            // FMOD Studio only looks up global parameters by name, so instance parameters are
+            // searched for in the loaded events to tell the two cases apart
+            Err(fmod::Error::Fmod {
+                code: FMOD_ERR_EVENT_NOTFOUND,
+                ..
+            }) => {
+                let error = if self.has_instance_parameter(parameter_name)? {
+                    AudioError::ParameterNotGlobal
+                } else {
+                    AudioError::ParameterNotFound
+                };
+                return Err(error)
+                    .wrap_err_with(|| format!("no global parameter named `{}`", parameter_name));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if description.flags & PARAMETER_FLAG_GLOBAL == 0 {
+            return Err(AudioError::ParameterNotGlobal.into());
+        }
+
+        Ok(description.id.into())
+    }
+
+    /// Checks whether any loaded event has an instance parameter by this name.
+    fn has_instance_parameter(&self, parameter_name: &str) -> AnyResult<bool> {
+        for description in &self.event_descriptions {
+            for index in 0..description.get_parameter_description_count()? {
+                let parameter = description.get_parameter_description_by_index(index)?;
+                if parameter.name.eq_ignore_ascii_case(parameter_name) {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Same as [`AudioEngine::set_global_parameters`], but with ids from
+    /// [`AudioEngine::global_parameter_id`].
+    ///
+    /// Every parameter is attempted, even if an earlier one fails, and the first error is
+    /// returned.
+    pub fn set_global_parameters_by_id(&self, parameters: &[(ParameterId, f32)]) -> AnyResult {
+        let mut first_error = None;
+        for (id, value) in parameters {
+            if let Err(err) = self.handle.set_parameter_by_id((*id).into(), *value, true) {
+                first_error.get_or_insert(err.into());
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Checks if any event with the given name is playing at all.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn is_event_playing(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult<bool> {
+        Ok(self.event_instance_count(event_name)? > 0)
+    }
+
+    /// Checks how many times a given event is playing.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn event_instance_count(&self, event_name: &(impl AsRef<str> + ?Sized)) -> AnyResult<u32> {
+        let event_descriptor = self.handle.get_event(self.event_name_as_ref(event_name)?)?;
+
+        Ok(event_descriptor.get_instance_count()? as u32)
+    }
+
+    /// Gets the playback state of a given event without holding onto its instance, such as for
+    /// state machines which only track event names. If there are no instances, this is
+    /// [`PlaybackState::Stopped`].
+    ///
+    /// If several instances exist, this is the state of whichever one FMOD lists first, which
+    /// isn't necessarily the oldest or the newest.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn event_playback_state(
+        &self,
+        event_name: &(impl AsRef<str> + ?Sized),
+    ) -> AnyResult<PlaybackState> {
+        let event_descriptor = self.handle.get_event(self.event_name_as_ref(event_name)?)?;
+
+        match event_descriptor.get_instance_list(1)?.first() {
+            Some(instance) => Ok(instance.get_playback_state()?.into()),
+            None => Ok(PlaybackState::Stopped),
+        }
+    }
+
+    /// Gets every instance of a given event which FMOD still holds, including ones fired with
+    /// [`AudioEngine::play_event`], so you can adjust copies of an event you didn't keep around,
+    /// such as boosting the nearest one of a looping ambience.
+    ///
+    /// This includes instances which have stopped but aren't released yet.
+    ///
+    /// You can provide an `&str`, but you are *highly* encouraged to make your own Enum which uses `AsRef` to convert
+    /// between the types required.
+    pub fn instances_of(
+        &self,
+        event_name: &(impl AsRef<str> + ?Sized),
+    ) -> AnyResult<Vec<EventInstance>> {
+        let event_descriptor = self.handle.get_event(self.event_name_as_ref(event_name)?)?;
+        let instance_count = event_descriptor.get_instance_count()?;
+        if instance_count == 0 {
+            return Ok(vec![]);
+        }
+
+        Ok(event_descriptor
+            .get_instance_list(instance_count)?
+            .into_iter()
+            .map(|instance| EventInstance(instance, self.coordinate_mapping))
+            .collect())
+    }
+
+    /// Gets the path of every event which has at least one instance that isn't stopped, which is
+    /// handy for a debug overlay of what's audible right now.
+    ///
+    /// This goes through every loaded event, so it's meant for debugging rather than for
+    /// calling in hot code.
+    pub fn playing_events(&self) -> AnyResult<Vec<String>> {
+        let mut playing_events = vec![];
+
+        for (event_name, description) in self.event_names.iter().zip(&self.event_descriptions) {
+            let instance_count = description.get_instance_count()?;
+            if instance_count == 0 {
+                continue;
+            }
+
+            for instance in description.get_instance_list(instance_count)? {
+                if instance.get_playback_state()? != fmod::PlaybackState::Stopped {
+                    playing_events.push(event_name.clone());
+                    break;
+                }
+            }
+        }
+
+        Ok(playing_events)
+    }
+
+    /// Counts the instances of every loaded event which aren't stopped yet. Instances which are
+    /// still fading out count too, so stopping everything and waiting for this to reach `0`
+    /// before [`AudioEngine::unload_banks`] avoids cutting sounds off mid-fade.
+    ///
+    /// Like [`AudioEngine::playing_events`], this goes through every loaded event, so it's meant
+    /// for shutdown rather than for calling in hot code.
+    pub fn active_instance_count(&self) -> AnyResult<u32> {
+        let mut active_instance_count = 0;
+
+        for description in &self.event_descriptions {
+            let instance_count = description.get_instance_count()?;
+            if instance_count == 0 {
+                continue;
+            }
+
+            for instance in description.get_instance_list(instance_count)? {
+                if instance.get_playback_state()? != fmod::PlaybackState::Stopped {
+                    active_instance_count += 1;
+                }
+            }
+        }
+
+        Ok(active_instance_count)
+    }
+
+    /// Counts every instance of every loaded event, whatever its playback state, such as to
+    /// keep the number of instances under a budget before spawning low priority sounds.
+    ///
+    /// FMOD has no engine-wide count, so this adds up the count of each loaded event. That's
+    /// cheaper than [`AudioEngine::active_instance_count`], as no instance is looked at, but it's
+    /// still one call per loaded event.
+    pub fn total_instance_count(&self) -> AnyResult<u32> {
+        let mut total_instance_count = 0;
+        for description in &self.event_descriptions {
+            total_instance_count += description.get_instance_count()? as u32;
+        }
+
+        Ok(total_instance_count)
+    }
+
+    /// Previews how loud an event would be at `distance` from the listener, without playing it.
+    /// The result is a factor from `0.0` (silent) to `1.0` (full volume), which is handy for
+    /// visualizing audio ranges in a level editor.
+    ///
+    /// This uses the event's min and max distance with the linear squared rolloff curve, which
+    /// is what FMOD Studio's spatializer uses by default. Custom curves set in FMOD Studio are not
+    /// visible through the API, so those won't be reflected here. Events which aren't 3D always
+    /// return `1.0`.
+    pub fn attenuation_at(
+        &self,
+        event_name: &(impl AsRef<str> + ?Sized),
+        distance: f32,
+    ) -> AnyResult<f32> {
+        let event_descriptor = self.handle.get_event(self.event_name_as_ref(event_name)?)?;
+        if !event_descriptor.is_3d()? {
+            return Ok(1.0);
+        }
+
+        let (min_distance, max_distance) = event_descriptor.get_min_max_distance()?;
+        if distance <= min_distance {
+            return Ok(1.0);
+        }
+        if distance >= max_distance {
+            return Ok(0.0);
+        }
+
+        let falloff = (distance - min_distance) / (max_distance - min_distance);
+
+        Ok((1.0 - falloff) * (1.0 - falloff))
+    }
+
+    /// Sets the position of the listener in the spatializer.
+    ///
+    /// See [`AudioEngine::set_listener_velocity`] and [`AudioEngine::set_listener_position_velocity`]
+    /// to set the velocity of the listener if that matters for your application. We will continue to use the velocity
+    /// last assigned to this function.
+    pub fn set_listener_position(&self, position: Vec2) -> AnyResult {
+        self.set_listener_position_velocity_3d(position.extend(0.0), self.listener_velocity.get())
+    }
+
+    /// Sets the velocity of the listener in the spatializer. Some spatializers have
+    /// the doppler effect enabled, so you'll want to set the velocity here too.
+    ///
+    /// We will use the last let listener position (set either with [`AudioEngine::set_listener_position`]
+    /// or with [`AudioEngine::set_listener_position_velocity`]) as the listener position.
+    pub fn set_listener_velocity(&self, velocity: Vec2) -> AnyResult {
+        self.set_listener_position_velocity_3d(self.listener_position.get(), velocity.extend(0.0))
+    }
+
+    /// Sets the position and velocity of the listener in the spatializer. This is provided to reduce
+    /// the number of FFI calls we do at once, if you're going to set both all the time.
+    ///
+    /// Note: the internally tracked `position` and `velocity` will only be updated when this function
+    /// returns `Ok`.
+    pub fn set_listener_position_velocity(&self, position: Vec2, velocity: Vec2) -> AnyResult {
+        self.set_listener_position_velocity_3d(position.extend(0.0), velocity.extend(0.0))
+    }
+
+    /// Sets the position and velocity of the listener in the spatializer, including elevation.
+    /// The `Vec2` setters above are the same as this with `z` set to `0.0`.
+    ///
+    /// The listener always faces `+y`, with `+z` as up. With [`Handedness::Right`], the default,
+    /// that puts `+x` to the listener's right. With [`Handedness::Left`], `+x` is to the left
+    /// instead, so pick the handedness which matches your own coordinates rather than negating them.
+    ///
+    /// Note: the internally tracked `position` and `velocity` will only be updated when this function
+    /// returns `Ok`.
+    pub fn set_listener_position_velocity_3d(&self, position: Vec3, velocity: Vec3) -> AnyResult {
+        self.set_listener_attributes_3d(
+            position,
+            velocity,
+            self.listener_attenuation_position.get(),
+        )
+    }
+
+    /// Sets the listener like [`AudioEngine::set_listener_position_velocity`], but with sounds
+    /// attenuated by their distance to `attenuation` rather than to the listener. In a third-person
+    /// game, the listener is the camera, which sounds are panned around, while attenuation is
+    /// usually from the player. `None` attenuates from the listener position again.
+    ///
+    /// The attenuation position is kept when the listener is moved with the other setters.
+    ///
+    /// Note: the internally tracked values will only be updated when this function returns `Ok`.
+    pub fn set_listener_attributes_full(
+        &self,
+        attributes: AudioPositionVelocity,
+        attenuation: Option<Vec2>,
+    ) -> AnyResult {
+        self.set_listener_attributes_3d(
+            attributes.position.extend(0.0),
+            attributes.velocity.extend(0.0),
+            attenuation.map(|attenuation| attenuation.extend(0.0)),
+        )
+    }
+
+    fn set_listener_attributes_3d(
+        &self,
+        position: Vec3,
+        velocity: Vec3,
+        attenuation_position: Option<Vec3>,
+    ) -> AnyResult {
+        let fmod_attenuation_position = attenuation_position.map(|attenuation_position| {
+            let attenuation_position = self.coordinate_mapping.map_to_fmod(attenuation_position);
+            fmod::Vector::new(
+                attenuation_position.x,
+                attenuation_position.y,
+                attenuation_position.z,
+            )
+        });
+        self.handle.set_listener_attributes(
+            0,
+            attributes_3d(&self.coordinate_mapping, position, velocity),
+            fmod_attenuation_position,
+        )?;
+
+        // update our internals
+        self.listener_position.set(position);
+        self.listener_velocity.set(velocity);
+        self.listener_attenuation_position.set(attenuation_position);
+
+        Ok(())
+    }
+
+    /// Sets how many listeners there are, such as one per player in split-screen. FMOD supports
+    /// up to 8, and each sound is heard from whichever listener is closest to it. Restrict which
+    /// listeners an instance is heard by with [`EventInstance::set_listener_mask`].
+    ///
+    /// The listener setters on the engine all move listener `0`. The default is `1`.
+    pub fn set_listener_count(&self, count: i32) -> AnyResult {
+        self.handle.set_num_listeners(count)?;
+
+        Ok(())
+    }
+
+    /// Gets how many listeners there are. See [`AudioEngine::set_listener_count`].
+    pub fn listener_count(&self) -> AnyResult<i32> {
+        Ok(self.handle.get_num_listeners()?)
+    }
+
+    /// Sets how much the listener at `index` counts towards attenuation, from `0.0` to `1.0`.
+    /// Crossfading the weights of two listeners blends smoothly between them, such as during
+    /// a camera cut, instead of sounds jumping to the new position.
+    ///
+    /// Listeners are counted with [`AudioEngine::set_listener_count`]. The default is `1.0`.
+    pub fn set_listener_weight(&self, index: i32, weight: f32) -> AnyResult {
+        self.handle.set_listener_weight(index, weight)?;
+
+        Ok(())
+    }
+
+    /// Gets the weight of the listener at `index`. See [`AudioEngine::set_listener_weight`].
+    pub fn listener_weight(&self, index: i32) -> AnyResult<f32> {
+        Ok(self.handle.get_listener_weight(index)?)
+    }
+
+    /// Sets the position and velocity of many event instances at once, which is the usual
+    /// "update all 3D sources" step of a frame. Each entry is `(instance, position, velocity)`.
+    /// `benches/attributes.rs` compares this with calling the per-instance setters.
+    ///
+    /// This stops at the first instance which fails to update and returns that error, so
+    /// instances after it keep their old position until the next call.
+    pub fn set_many_instance_positions(
+        &self,
+        updates: &[(&EventInstance, Vec2, Vec2)],
+    ) -> AnyResult {
+        for (instance, position, velocity) in updates {
+            instance.0.set_3d_attributes(attributes_3d(
+                &instance.1,
+                position.extend(0.0),
+                velocity.extend(0.0),
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    /// Places an instance at `offset` from the listener, such as a sound which follows the
+    /// camera. The instance gets the listener's velocity too, so it moves along with the listener
+    /// without any doppler shift.
+    ///
+    /// This uses the listener position cached by the engine's setters, which is listener `0`'s,
+    /// so call it again whenever the listener moves. See [`AudioEngine::listener_position`].
+    pub fn set_position_relative_to_listener(
+        &self,
+        instance: &EventInstance,
+        offset: Vec2,
+    ) -> AnyResult {
+        instance.set_position_velocity_3d(
+            self.listener_position.get() + offset.extend(0.0),
+            self.listener_velocity.get(),
+        )
+    }
+
+    /// Gets the internally held listener position. This is the value that was last set using
+    /// [`AudioEngine::set_listener_position`] or [`AudioEngine::set_listener_position_velocity`].
+    ///
+    /// Defaults to [`Vec2::ZERO`].
+    pub fn listener_position(&self) -> Vec2 {
+        self.listener_position.get().truncate()
+    }
+
+    /// Same as [`AudioEngine::listener_position`], but including elevation.
+    ///
+    /// Defaults to [`Vec3::ZERO`].
+    pub fn listener_position_3d(&self) -> Vec3 {
+        self.listener_position.get()
+    }
+
+    /// Gets the internally held listener velocity. This is the value that was last set using
+    /// [`AudioEngine::set_listener_velocity`] or [`AudioEngine::set_listener_position_velocity`].
+    ///
+    /// Defaults to [`Vec2::ZERO`].
+    pub fn listener_velocity(&self) -> Vec2 {
+        self.listener_velocity.get().truncate()
+    }
+
+    /// Same as [`AudioEngine::listener_velocity`], but including elevation.
+    ///
+    /// Defaults to [`Vec3::ZERO`].
+    pub fn listener_velocity_3d(&self) -> Vec3 {
+        self.listener_velocity.get()
+    }
+
+    /// Gets the attenuation position last set with [`AudioEngine::set_listener_attributes_full`],
+    /// or `None` if sounds are attenuated from the listener position.
+    pub fn listener_attenuation_position(&self) -> Option<Vec2> {
+        self.listener_attenuation_position
+            .get()
+            .map(|attenuation_position| attenuation_position.truncate())
+    }
+
+    /// Reads the attributes of the listener at `index` back from FMOD, mapped into your axes.
+    ///
+    /// The listener setters on the engine keep their own copy of what they last set, which is
+    /// what the other getters return. This asks FMOD instead, so it also sees changes made
+    /// elsewhere, such as through a command replay, and works for every listener, not just `0`.
+    pub fn listener_attributes(&self, index: i32) -> AnyResult<ListenerAttributes> {
+        let (attributes, _) = self.handle.get_listener_attributes(index)?;
+        let map = |vector: fmod::Vector| {
+            self.coordinate_mapping
+                .map_from_fmod(Vec3::new(vector.x, vector.y, vector.z))
+        };
+
+        Ok(ListenerAttributes {
+            position: map(attributes.position),
+            velocity: map(attributes.velocity),
+            forward: map(attributes.forward),
+            up: map(attributes.up),
+        })
+    }
+
+    /// Gives access to the [`CoreSystem`] which the studio system runs on top of. This is where
+    /// device level settings, such as the output driver, live.
+    pub fn core_system(&self) -> AnyResult<CoreSystem> {
+        Ok(CoreSystem(self.handle.get_core_system()?))
+    }
+
+    /// Gives access to the master [`ChannelGroup`], which everything is mixed into before it
+    /// reaches the output. Add a DSP here to process the whole mix, such as a limiter created
+    /// with [`CoreSystem::create_dsp_by_type`].
+    pub fn master_channel_group(&self) -> AnyResult<ChannelGroup> {
+        Ok(ChannelGroup(
+            self.core_system()?.0.get_master_channel_group()?,
+        ))
+    }
+
+    /// Starts capturing the final mix, so it can be read back with [`OutputCapture::read`]. This
+    /// is meant for tests, such as checking that an event actually produces non-silent audio,
+    /// and pairs well with [`AudioEngine::new_headless`].
+    ///
+    /// The capture keeps running until the returned [`OutputCapture`] is dropped.
+    ///
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn capture_output(&self) -> AnyResult<OutputCapture> {
+        OutputCapture::new(&self.core_system()?.0)
+    }
+
+    /// Creates empty level geometry with room for `max_polygons` polygons and `max_vertices`
+    /// vertices in total, such as the walls of a level. Once polygons are added with
+    /// [`Geometry::add_polygon`], FMOD occludes sounds behind them by itself, rather than
+    /// occlusion being set by hand with [`EventInstance::set_occlusion`].
+    ///
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn create_geometry(&self, max_polygons: i32, max_vertices: i32) -> AnyResult<Geometry> {
+        let geometry = self
+            .core_system()?
+            .0
+            .create_geometry(max_polygons, max_vertices)?;
+
+        Ok(Geometry(geometry, self.coordinate_mapping))
+    }
+
+    /// Starts recording every command sent to FMOD Studio into the file at `path`, until
+    /// [`AudioEngine::stop_command_capture`]. Attach the file to a bug report, and it can be
+    /// played back exactly with [`AudioEngine::load_command_replay`].
+    ///
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn start_command_capture(
+        &self,
+        path: &std::path::Path,
+        flags: CommandCaptureFlags,
+    ) -> AnyResult {
+        command_capture::start_command_capture(&self.handle, path, flags)
+    }
+
+    /// Stops the capture started with [`AudioEngine::start_command_capture`].
+    ///
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn stop_command_capture(&self) -> AnyResult {
+        command_capture::stop_command_capture(&self.handle)
+    }
+
+    /// Loads a file recorded with [`AudioEngine::start_command_capture`], to replay it on this
+    /// engine with [`CommandReplay::play`]. Unless [`CommandReplayFlags::SKIP_BANK_LOAD`] is set,
+    /// the banks are loaded from the paths they had when they were captured.
+    ///
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_command_replay(
+        &self,
+        path: &std::path::Path,
+        flags: CommandReplayFlags,
+    ) -> AnyResult<CommandReplay> {
+        CommandReplay::load(&self.handle, path, flags)
+    }
+
+    /// Scales the doppler effect of every event, such as lowering it during bullet-time for a
+    /// dreamy effect. `1.0` is normal, `0.0` disables doppler, and `2.0` doubles the pitch shift.
+    ///
+    /// The default is `1.0`.
+    pub fn set_doppler_scale(&self, scale: f32) -> AnyResult {
+        let system = self.core_system()?.0;
+        let (_, distance_factor, rolloff_scale) = system.get_3d_settings()?;
+        system.set_3d_settings(scale, distance_factor, rolloff_scale)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the doppler scale set with [`AudioEngine::set_doppler_scale`].
+    pub fn doppler_scale(&self) -> AnyResult<f32> {
+        Ok(self.core_system()?.0.get_3d_settings()?.0)
+    }
+
+    /// Sets how many units make a meter, which FMOD uses for doppler. For example, if your
+    /// positions are in pixels with 100 pixels to a meter, set this to `100.0`.
+    ///
+    /// The default is `1.0`.
+    pub fn set_distance_factor(&self, factor: f32) -> AnyResult {
+        let system = self.core_system()?.0;
+        let (doppler_scale, _, rolloff_scale) = system.get_3d_settings()?;
+        system.set_3d_settings(doppler_scale, factor, rolloff_scale)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the distance factor set with [`AudioEngine::set_distance_factor`].
+    pub fn distance_factor(&self) -> AnyResult<f32> {
+        Ok(self.core_system()?.0.get_3d_settings()?.1)
+    }
+
+    /// Scales how quickly sounds attenuate with distance. This only affects sounds using the
+    /// core API's inverse rolloff, and not the distance attenuation set up in FMOD Studio.
+    ///
+    /// The default is `1.0`.
+    pub fn set_rolloff_scale(&self, scale: f32) -> AnyResult {
+        let system = self.core_system()?.0;
+        let (doppler_scale, distance_factor, _) = system.get_3d_settings()?;
+        system.set_3d_settings(doppler_scale, distance_factor, scale)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the rolloff scale set with [`AudioEngine::set_rolloff_scale`].
+    pub fn rolloff_scale(&self) -> AnyResult<f32> {
+        Ok(self.core_system()?.0.get_3d_settings()?.2)
+    }
+
+    /// Gets the version of the FMOD library in use as `(major, minor, patch)`, such as
+    /// `(2, 2, 22)` for FMOD 2.02.22. This is handy to put in bug reports.
+    ///
+    /// On wasm, this is the version of FMOD's HTML5 build that was loaded.
+    pub fn fmod_version(&self) -> AnyResult<(u16, u16, u16)> {
+        // the version is encoded as 0xaaaabbcc, where each part reads as decimal when written
+        // in hex, so 2.02.22 is 0x00020222
+        let version = self.core_system()?.0.get_version()?;
+        let decode = |hex: u32| {
+            format!("{:x}", hex)
+                .parse::<u16>()
+                .map_err(|_| eyre!("unexpected FMOD version {:#010x}", version))
+        };
+
+        Ok((
+            decode(version >> 16)?,
+            decode((version >> 8) & 0xFF)?,
+            decode(version & 0xFF)?,
+        ))
+    }
+
+    /// Sets the properties of one of the core system's global reverbs, such as
+    /// [`ReverbProperties::cave`]. This is a fallback for events without reverb of their own
+    /// in FMOD Studio. There are 4 global reverbs, so `index` goes from 0 to 3.
+    ///
+    /// Use [`ReverbProperties::off`] to turn a reverb off again.
+    pub fn set_reverb_properties(&self, index: i32, props: ReverbProperties) -> AnyResult {
+        self.core_system()?
+            .0
+            .set_reverb_properties(index, props.into())?;
+
+        Ok(())
+    }
+
+    /// Switches to the given [`Environment`], such as when the player enters a cave. This sets
+    /// global reverb `0` to [`Environment::reverb_properties`], and the global parameter
+    /// `Environment` to [`Environment::parameter_value`], so events authored in FMOD Studio can
+    /// react to it too.
+    ///
+    /// Projects without an `Environment` parameter only get the reverb, while one which isn't
+    /// global returns [`AudioError::ParameterNotGlobal`]. To leave every zone, set reverb `0`
+    /// to [`ReverbProperties::off`] with [`AudioEngine::set_reverb_properties`].
+    pub fn set_environment(&self, zone: Environment) -> AnyResult {
+        self.set_reverb_properties(0, zone.reverb_properties())?;
+
+        let id = match self.global_parameter_id(ENVIRONMENT_PARAMETER) {
+            Ok(id) => id,
+            Err(err)
+                if err.downcast_ref::<AudioError>() == Some(&AudioError::ParameterNotFound) =>
+            {
+                return Ok(())
+            }
+            Err(err) => return Err(err),
+        };
+        self.handle
+            .set_parameter_by_id(id.into(), zone.parameter_value(), true)?;
+
+        Ok(())
+    }
+
+    /// Suspends the mixer thread and relinquishes usage of the audio device. Use this when the
+    /// operating system or browser takes the audio device away, such as when the app is sent to
+    /// the background or the tab loses focus. Unlike [`AudioEngine::set_paused`], this
+    /// stops FMOD from mixing at all.
+    ///
+    /// Call [`AudioEngine::resume_mixer`] to get the audio device back.
+    pub fn suspend_mixer(&self) -> AnyResult {
+        self.core_system()?.mixer_suspend()
+    }
+
+    /// Resumes the mixer thread and reacquires the audio device, after
+    /// [`AudioEngine::suspend_mixer`] was called.
+    pub fn resume_mixer(&self) -> AnyResult {
+        self.core_system()?.mixer_resume()
+    }
+
+    /// This must be called once per frame. At this point, all commands are *actually* submitted and
+    /// callbacks occur. Basically, the good stuff happens here.
+    ///
+    /// Commands queued up by [`AudioEngineHandle`]s are run first, so they're submitted to FMOD
+    /// in the same frame. FMOD is updated even if one of them fails, and the first error is
+    /// returned.
+    ///
+    /// This gets called in [mwe::main_loop] automatically.
+    pub fn update(&self) -> AnyResult {
+        let commands_result = self.run_queued_commands();
+        let result = commands_result.and(self.update_studio());
+
+        // woken even if updating failed, so they can see the error for themselves
+        #[cfg(feature = "async")]
+        for waker in self.update_wakers.take() {
+            waker.wake();
+        }
+
+        result
+    }
+
+    /// Does the work of [`AudioEngine::update`].
+    fn update_studio(&self) -> AnyResult {
+        if self.asset_id.get().is_none() {
+            return Ok(());
+        }
+
+        // one instance failing to move shouldn't hold back the others, or FMOD itself
+        let mut first_error = None;
+        {
+            let mut position_sources = self.position_sources.borrow_mut();
+            position_sources.retain(|source| source.instance.is_valid());
+            for source in position_sources.iter_mut() {
+                let (position, velocity) = (source.source)();
+                let result = source.instance.set_3d_attributes(attributes_3d(
+                    &self.coordinate_mapping,
+                    position.extend(0.0),
+                    velocity.extend(0.0),
+                ));
+                if let Err(err) = result {
+                    first_error.get_or_insert(err.into());
+                }
+            }
+        }
+
+        if let Err(err) = self.handle.update() {
+            first_error.get_or_insert(err.into());
+        }
+
+        // released instances become invalid once they stop, so this is where we drop them
+        self.tracked_instances
+            .borrow_mut()
+            .retain(|instance| instance.is_valid());
+        self.globally_paused_instances
+            .borrow_mut()
+            .retain(|instance| instance.is_valid());
+        // and FMOD doesn't free the DSPs we added to their channel groups for us
+        self.pitch_shifters.borrow_mut().retain(|pitch_shifter| {
+            let is_valid = pitch_shifter.instance.is_valid();
+            if !is_valid {
+                pitch_shifter.dsp.release().ok();
+            }
+            is_valid
+        });
+
+        self.deliver_bank_events();
+
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Calls `callback` whenever a bank is loaded or unloaded, or finishes loading its sample
+    /// data, so a streaming world can react to banks instead of polling their loading states.
+    /// Setting another callback replaces this one.
+    ///
+    /// Events are queued up as they happen, and `callback` is called with them at the end of
+    /// [`AudioEngine::update`], after FMOD has updated, in the order they happened. So a bank
+    /// loaded between two updates is reported by the second one. Events which happen before
+    /// any callback is set are dropped during that update.
+    ///
+    /// Sample data is only reported the first time it finishes loading for each bank, such as
+    /// after [`AudioEngine::preload_event`]. FMOD has no callback for that, so every update
+    /// polls the sample loading state of each bank which hasn't reported it yet.
+    ///
+    /// `callback` can call back into the engine, and setting another callback from within it
+    /// replaces it from the next update on.
+    pub fn set_bank_callback(&self, callback: impl FnMut(BankEvent) + 'static) {
+        *self.bank_callback.borrow_mut() = Some(BankCallback(Box::new(callback)));
+    }
+
+    /// Stops every instance started with the `play_event` family of methods which is still
+    /// playing. This is a panic button to silence everything, such as between scenes.
+    ///
+    /// This only works with [`AudioEngineConfig::track_instances`] enabled, and returns an error
+    /// otherwise. Instances created with [`AudioEngine::create_event_instance`] aren't tracked.
+    pub fn stop_all(&self, mode: StopMode) -> AnyResult {
+        if !self.track_instances {
+            return Err(eyre!(
+                "stop_all requires AudioEngineConfig::track_instances to be enabled"
+            ));
+        }
+
+        let mut tracked_instances = self.tracked_instances.borrow_mut();
+        tracked_instances.retain(|instance| instance.is_valid());
+        for instance in tracked_instances.iter() {
+            instance.stop(mode.into())?;
+        }
+
+        Ok(())
+    }
+
+    /// Counts the instances started with the `play_event` family of methods which FMOD has
+    /// virtualized, meaning they're silent and not taking up a real channel, such as to see
+    /// when more is being spawned than FMOD can actually play. See [`EventInstance::is_virtual`].
+    ///
+    /// This only works with [`AudioEngineConfig::track_instances`] enabled, and returns an error
+    /// otherwise. Instances created with [`AudioEngine::create_event_instance`] aren't tracked.
+    pub fn virtual_instance_count(&self) -> AnyResult<u32> {
+        if !self.track_instances {
+            return Err(eyre!(
+                "virtual_instance_count requires AudioEngineConfig::track_instances to be enabled"
+            ));
+        }
+
+        let mut virtual_instance_count = 0;
+        for instance in self.tracked_instances.borrow().iter() {
+            // released instances stay tracked until the next update
+            if instance.is_valid() && instance.is_virtual()? {
+                virtual_instance_count += 1;
+            }
+        }
+
+        Ok(virtual_instance_count)
+    }
+
+    /// Creates a handle which other threads can use to queue up commands for this engine,
+    /// such as playing events from gameplay code while the engine lives on an audio thread.
+    /// Every handle feeds the same queue. See [`AudioEngineHandle`].
+    pub fn engine_handle(&self) -> AudioEngineHandle {
+        AudioEngineHandle {
+            sender: self.command_sender.clone(),
+        }
+    }
+
+    /// Runs every command queued up by the [`AudioEngineHandle`]s so far, in the order they were
+    /// sent. Every command is run, even if an earlier one fails, and the first error is returned.
+    fn run_queued_commands(&self) -> AnyResult {
+        let mut first_error = None;
+        while let Ok(command) = self.command_receiver.try_recv() {
+            let result = match command {
+                AudioCommand::PlayEvent(event_name) => self.play_event(&event_name).map(|_| ()),
+                AudioCommand::SetListenerPosition(position) => self.set_listener_position(position),
+                AudioCommand::SetListenerPositionVelocity(attributes) => {
+                    self.set_listener_position_velocity(attributes.position, attributes.velocity)
+                }
+                AudioCommand::SetGlobalParameter(parameter_name, value) => {
+                    self.set_global_parameter(&parameter_name, value)
+                }
+                AudioCommand::SetGlobalPause(paused) => self.set_global_pause(paused),
+                AudioCommand::StopAll(mode) => self.stop_all(mode),
+            };
+
+            if let Err(err) = result {
+                first_error.get_or_insert(err);
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Remembers an instance we started, if [`AudioEngineConfig::track_instances`] is enabled.
+    fn track(&self, event: &EventInstance) {
+        if self.track_instances {
+            self.tracked_instances.borrow_mut().push(event.0.clone());
+        }
+    }
+
+    /// Checks whether FMOD mixes following the wall-clock, which is every [`OutputMode`] but
+    /// [`OutputMode::NoSoundNrt`] and, on native, `OutputMode::WavWriterNrt`. Game loops shared
+    /// with tests can use this to decide whether to sleep between frames, as a non-realtime
+    /// engine only advances when it's updated.
+    pub fn is_realtime(&self) -> bool {
+        !self.output.is_non_realtime()
+    }
+
+    /// Advances FMOD by at least `samples` samples of audio. This only works with
+    /// [`OutputMode::NoSoundNrt`] and, on native, `OutputMode::WavWriterNrt`, and returns an
+    /// error otherwise, as realtime outputs mix following the wall-clock instead.
+    ///
+    /// In non-realtime mode, each [`AudioEngine::update`] mixes exactly one DSP block, so this
+    /// calls `update` as many times as it takes to cover `samples`, rounded up to whole blocks.
+    /// Calling `update` yourself advances FMOD by one block too, so the two can be mixed freely.
+    /// The exception is before any bank is loaded, when `update` does nothing, while this still
+    /// mixes the blocks.
+    pub fn update_by(&self, samples: u32) -> AnyResult {
+        if !self.output.is_non_realtime() {
+            return Err(eyre!(
+                "update_by requires a non-realtime output, but the engine uses {:?}",
+                self.output
+            ));
+        }
+
+        let (block_size, _) = self.core_system()?.0.get_dsp_buffer_size()?;
+        for _ in 0..samples.div_ceil(block_size) {
+            // `update` leaves FMOD alone until a bank is loaded, but the samples are mixed anyway
+            if self.asset_id.get().is_some() {
+                self.update()?;
+            } else {
+                self.run_queued_commands()?;
+                self.handle.update()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the next number out of our random number generator. This is splitmix64, which is
+    /// plenty for picking between sounds.
+    fn next_random(&self) -> u64 {
+        let state = self.random_state.get().wrapping_add(0x9E3779B97F4A7C15);
+        self.random_state.set(state);
+
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Gets the master bus, which every other bus routes through.
+    fn master_bus(&self) -> AnyResult<fmod::Bus> {
+        Ok(self.handle.get_bus("bus:/")?)
+    }
+
+    /// Starts an instance for the `play_event` family of methods. One-shots are always marked for
+    /// release, while looping events only are with `always_release`.
+    fn start_played_event(&self, event: &EventInstance, always_release: bool) -> AnyResult {
+        event.start()?;
+        if always_release || event.0.get_description()?.is_oneshot()? {
+            event.mark_for_release()?;
+        }
+        self.track(event);
+
+        Ok(())
+    }
+
+    /// Queues up banks whose sample data finished loading, then hands every queued bank event
+    /// to the bank callback, if there is one.
+    fn deliver_bank_events(&self) {
+        let events = {
+            let mut pending_bank_events = self.pending_bank_events.borrow_mut();
+            let mut bank_samples_reported = self.bank_samples_reported.borrow_mut();
+            for (bank, reported) in self.banks.iter().zip(bank_samples_reported.iter_mut()) {
+                if !*reported
+                    && matches!(
+                        bank.get_sample_loading_state(),
+                        Ok(fmod::LoadingState::Loaded)
+                    )
+                {
+                    *reported = true;
+                    pending_bank_events.push(BankEvent::SampleDataLoaded(BankHandle(bank.clone())));
+                }
+            }
+
+            std::mem::take(&mut *pending_bank_events)
+        };
+
+        // the callback is taken out while it runs, so it can call back into the engine, even to
+        // set another callback
+        let callback = self.bank_callback.borrow_mut().take();
+        if let Some(mut callback) = callback {
+            for event in events {
+                (callback.0)(event);
+            }
+
+            let mut bank_callback = self.bank_callback.borrow_mut();
+            if bank_callback.is_none() {
+                *bank_callback = Some(callback);
+            }
+        }
+    }
+
+    /// Converts the given event name back into a string, checking that it's either a path
+    /// starting with a scheme such as `event:/` or `snapshot:/`, or a GUID in braces, which are
+    /// the two things FMOD looks events up by. Anything else is [`AudioError::InvalidEventPath`].
+    fn event_name_as_ref<'a>(
+        &self,
+        event_name: &'a (impl AsRef<str> + ?Sized),
+    ) -> AnyResult<&'a str> {
         let event_name = event_name.as_ref();
 
+        let is_path = event_name.split_once(":/").is_some_and(|(scheme, _)| {
+            !scheme.is_empty() && scheme.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+        let is_guid = event_name.starts_with('{') && event_name.parse::<Guid>().is_ok();
+        if !is_path && !is_guid {
+            return Err(AudioError::InvalidEventPath)
+                .wrap_err_with(|| format!("can't look up an event named `{}`", event_name));
+        }
+
+        Ok(event_name)
+    }
+}
+
+/// Calls [`AudioEngine::update`] on every engine, such as when UI and world audio have their own.
+/// Unlike calling them one after the other with `?`, every engine is updated even if an earlier
+/// one fails, and all of the errors are returned together.
+pub fn update_all(engines: &[&AudioEngine]) -> Result<(), Vec<color_eyre::Report>> {
+    let errors: Vec<_> = engines
+        .iter()
+        .filter_map(|engine| engine.update().err())
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Banks which finished loading in the background, as returned by
+/// [`AudioEngine::load_bank_files_async`]. Their events are added to the engine with
+/// [`AudioEngine::add_loaded_banks`].
+///
+/// Only available with the `async` feature.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+#[must_use = "the events of the banks aren't added until `AudioEngine::add_loaded_banks`"]
+pub struct LoadedBanks(Vec<fmod::Bank>);
+
+/// What a call to [`AudioEngine::load_bank_files_from_memory`] loaded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct BankLoadReport {
+    /// How many banks were loaded.
+    pub banks_loaded: usize,
+    /// How many events the loaded banks contain.
+    pub events_added: usize,
+    /// How many of those events got a path like `event:/Music/Level 02`. If this is less than
+    /// `events_added`, the strings bank probably wasn't loaded first, so the rest can only be
+    /// used by their GUIDs.
+    pub names_resolved: usize,
+}
+
+/// Memory used by an FMOD object, in bytes, as returned by [`EventInstance::memory_usage`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct MemoryUsage {
+    /// Memory used by the object itself.
+    pub exclusive: u64,
+    /// Memory used by the object and everything it holds onto, such as the instances it plays.
+    pub inclusive: u64,
+    /// Memory used by the sample data the object holds onto.
+    pub sample_data: u64,
+}
+
+impl From<fmod::MemoryUsage> for MemoryUsage {
+    fn from(value: fmod::MemoryUsage) -> Self {
+        Self {
+            exclusive: value.exclusive.max(0) as u64,
+            inclusive: value.inclusive.max(0) as u64,
+            sample_data: value.sampledata.max(0) as u64,
+        }
+    }
+}
+
+/// How an event started, as returned by [`AudioEngine::play_event_detailed`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct StartInfo {
+    /// Whether FMOD virtualized the instance right away, such as when the polyphony limit of the
+    /// event was already reached. Virtual instances are silent until they become real again.
+    pub was_virtual: bool,
+    /// The priority of the instance's channels, from `0` (most important) to `256`, or `-1` if
+    /// the priority set in FMOD Studio is used. See [`EventProperty::ChannelPriority`].
+    pub channel_priority: f32,
+}
+
+/// Errors which come from this crate itself, rather than from FMOD. These are returned
+/// wrapped in the usual error report, so check for them with
+/// `report.downcast_ref::<AudioError>()`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AudioError {
+    /// No bank has finished loading yet, so there's nothing to play. See [`AudioEngine::is_ready`].
+    NotReady,
+    /// Events were loaded without the strings bank, so they have no `event:/` paths and can't be
+    /// played by name. Load the `.strings` bank first. This is only returned when
+    /// [`AudioEngineConfig::allow_missing_strings_bank`] is turned off.
+    StringsBankMissing,
+    /// The parameter is set on each event instance rather than globally, so set it with
+    /// [`EventInstance::set_parameter_by_name`] instead.
+    ParameterNotGlobal,
+    /// No loaded event has a parameter by this name, global or not.
+    ParameterNotFound,
+    /// The event name is neither a path starting with a scheme such as `event:/` nor a GUID like
+    /// `{01234567-89ab-cdef-0123-456789abcdef}`, so FMOD can't look it up.
+    InvalidEventPath,
+}
+
+impl Display for AudioError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AudioError::NotReady => write!(f, "no bank has finished loading yet"),
+            AudioError::StringsBankMissing => write!(
+                f,
+                "events were loaded without their names, load the strings bank first"
+            ),
+            AudioError::ParameterNotGlobal => write!(
+                f,
+                "the parameter isn't global, set it on each event instance instead"
+            ),
+            AudioError::ParameterNotFound => write!(f, "no loaded event has this parameter"),
+            AudioError::InvalidEventPath => write!(
+                f,
+                "event names must be a path such as `event:/` or a GUID in braces"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AudioError {}
+
+/// Settings used to initialize an [`AudioEngine`] with [`AudioEngine::new_with_config`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioEngineConfig {
+    /// Allows FMOD Studio to connect to the game for live update and profiling.
+    pub live_update: bool,
+    /// Where the mixed audio goes. Defaults to [`OutputMode::Device`].
+    pub output: OutputMode,
+    /// Which coordinate system positions are in. Defaults to [`Handedness::Right`].
+    pub handedness: Handedness,
+    /// How positions and velocities given to the engine map onto FMOD's axes. Defaults to
+    /// [`CoordinateMapping::IDENTITY`].
+    pub coordinate_mapping: CoordinateMapping,
+    /// The key used to decrypt banks built with encryption in FMOD Studio. Banks which
+    /// aren't encrypted can still be loaded with `LoadBank::UNENCRYPTED`, see
+    /// [`AudioEngine::load_bank_files_from_memory_with_flags`].
+    ///
+    /// FMOD only takes this before initializing, so it can't be changed later.
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub encryption_key: Option<String>,
+    /// Keeps track of the instances started with the `play_event` family of methods, so they can
+    /// all be stopped with [`AudioEngine::stop_all`]. Stopped instances are forgotten during
+    /// [`AudioEngine::update`].
+    pub track_instances: bool,
+    /// Allows loading events without the strings bank, for projects which only refer to events
+    /// by GUID. Defaults to `true`. Turn it off to have loading return
+    /// [`AudioError::StringsBankMissing`] when the strings bank was forgotten.
+    pub allow_missing_strings_bank: bool,
+    /// The sample rate FMOD mixes at, such as `48_000`. Defaults to `None`, which leaves it to
+    /// FMOD, which mixes at 48kHz.
+    ///
+    /// FMOD only takes this before initializing, so it can't be changed later.
+    pub sample_rate: Option<u32>,
+    /// The speaker layout FMOD mixes for. Defaults to [`SpeakerMode::Default`], which matches the
+    /// output device.
+    ///
+    /// FMOD only takes this before initializing, so it can't be changed later.
+    pub speaker_mode: SpeakerMode,
+    /// The number of samples FMOD mixes at once. Defaults to `None`, which leaves it to FMOD,
+    /// which uses `1024` on most platforms.
+    ///
+    /// Latency is `dsp_block_size * dsp_buffer_count` samples, so smaller blocks and fewer buffers
+    /// get sounds out sooner, which matters for rhythm games. The tradeoff is that FMOD mixes more
+    /// often, which costs more CPU, and has less slack when a mix runs late, which is heard as
+    /// stuttering. Try `256` and `4` and go down from there.
+    ///
+    /// FMOD only takes this before initializing, so it can't be changed later.
+    pub dsp_block_size: Option<u32>,
+    /// The number of blocks of [`AudioEngineConfig::dsp_block_size`] samples FMOD queues up for
+    /// the output. Defaults to `None`, which leaves it to FMOD, which uses `4` on most
+    /// platforms.
+    ///
+    /// FMOD only takes this before initializing, so it can't be changed later.
+    pub dsp_buffer_count: Option<u32>,
+    /// Extra flags for the studio system, added to the ones the other settings imply, such as
+    /// `SYNCHRONOUS_UPDATE` to run FMOD Studio's processing inside [`AudioEngine::update`]
+    /// rather than on its own thread. Defaults to `None`, which adds nothing.
+    ///
+    /// FMOD only takes this before initializing, so it can't be changed later.
+    pub studio_init_flags: Option<fmod::StudioInit>,
+    /// Extra flags for the core system, added to the ones the other settings imply, such as
+    /// `THREAD_UNSAFE` alongside a `SYNCHRONOUS_UPDATE` studio system. Defaults to `None`,
+    /// which adds nothing.
+    ///
+    /// `THREAD_UNSAFE` without `SYNCHRONOUS_UPDATE` is rejected, as FMOD Studio's own thread
+    /// would then call into the core system at the same time as we do.
+    ///
+    /// FMOD only takes this before initializing, so it can't be changed later.
+    pub init_flags: Option<fmod::Init>,
+    /// The file [`OutputMode::WavWriter`] and [`OutputMode::WavWriterNrt`] write to. Defaults to
+    /// `None`, which leaves it to FMOD, which writes `fmodoutput.wav` in the working directory.
+    ///
+    /// FMOD only takes this before initializing, so it can't be changed later.
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub output_file: Option<std::path::PathBuf>,
+}
+
+impl Default for AudioEngineConfig {
+    fn default() -> Self {
+        Self {
+            live_update: false,
+            output: OutputMode::default(),
+            handedness: Handedness::default(),
+            coordinate_mapping: CoordinateMapping::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            encryption_key: None,
+            track_instances: false,
+            allow_missing_strings_bank: true,
+            sample_rate: None,
+            speaker_mode: SpeakerMode::default(),
+            dsp_block_size: None,
+            dsp_buffer_count: None,
+            studio_init_flags: None,
+            init_flags: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            output_file: None,
+        }
+    }
+}
+
+/// The handedness of the coordinate system positions are given in, set with
+/// [`AudioEngineConfig::handedness`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum Handedness {
+    /// Right-handed coordinates, where `x` cross `y` points along `+z`.
+    #[default]
+    Right,
+    /// Left-handed coordinates, where `x` cross `y` points along `-z`. This is FMOD's own default.
+    Left,
+}
+
+impl From<Handedness> for fmod::Init {
+    fn from(value: Handedness) -> Self {
+        match value {
+            Handedness::Right => fmod::Init::RIGHTHANDED_3D,
+            // left-handed is the absence of the flag
+            Handedness::Left => fmod::Init::NORMAL,
+        }
+    }
+}
+
+/// Maps positions and velocities from your game's axes onto FMOD's, set with
+/// [`AudioEngineConfig::coordinate_mapping`]. Each field is the FMOD vector that one of your
+/// axes points along. This applies to the listener and to every event instance.
+///
+/// For example, if your 2D game's `+y` points down the screen while FMOD's listener faces `+y`,
+/// map `y_axis` to `-Vec3::Y`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CoordinateMapping {
+    /// Where your `+x` points in FMOD's space.
+    pub x_axis: Vec3,
+    /// Where your `+y` points in FMOD's space.
+    pub y_axis: Vec3,
+    /// Where your `+z` points in FMOD's space.
+    pub z_axis: Vec3,
+}
+
+impl CoordinateMapping {
+    /// Keeps every axis as is.
+    pub const IDENTITY: Self = Self {
+        x_axis: Vec3::X,
+        y_axis: Vec3::Y,
+        z_axis: Vec3::Z,
+    };
+
+    /// Maps a vector from your axes to FMOD's.
+    pub fn map_to_fmod(&self, value: Vec3) -> Vec3 {
+        self.x_axis * value.x + self.y_axis * value.y + self.z_axis * value.z
+    }
+
+    /// Maps a vector from FMOD's axes back to yours.
+    pub fn map_from_fmod(&self, value: Vec3) -> Vec3 {
+        Mat3::from_cols(self.x_axis, self.y_axis, self.z_axis).inverse() * value
+    }
+}
+
+impl Default for CoordinateMapping {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Where FMOD sends the mixed audio, set with [`AudioEngineConfig::output`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum OutputMode {
+    /// Plays through the audio device. On the web, this is picked by the browser.
+    #[default]
+    Device,
+    /// Mixes in realtime like [`OutputMode::Device`], but doesn't play anything.
+    NoSound,
+    /// Doesn't play anything, and only mixes when [`AudioEngine::update`] is called, rather than
+    /// following the wall-clock. This is what [`AudioEngine::new_headless`] uses, for CI and tests.
+    NoSoundNrt,
+    /// Mixes in realtime like [`OutputMode::Device`], but writes the mix to the WAV file set with
+    /// [`AudioEngineConfig::output_file`] instead of playing it.
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    WavWriter,
+    /// Writes the mix to the WAV file set with [`AudioEngineConfig::output_file`], and only mixes
+    /// when [`AudioEngine::update`] is called like [`OutputMode::NoSoundNrt`]. Driving it with
+    /// [`AudioEngine::update_by`] renders a sequence the same way every time, such as for
+    /// capturing a trailer, no matter how long each frame took.
+    /// Only available on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    WavWriterNrt,
+}
+
+impl OutputMode {
+    /// Whether FMOD only mixes when it's updated, rather than following the wall-clock.
+    fn is_non_realtime(self) -> bool {
+        match self {
+            OutputMode::Device | OutputMode::NoSound => false,
+            OutputMode::NoSoundNrt => true,
+            #[cfg(not(target_arch = "wasm32"))]
+            OutputMode::WavWriter => false,
+            #[cfg(not(target_arch = "wasm32"))]
+            OutputMode::WavWriterNrt => true,
+        }
+    }
+}
+
+impl From<OutputMode> for fmod::OutputType {
+    fn from(value: OutputMode) -> Self {
+        match value {
+            OutputMode::Device => fmod::OutputType::Autodetect,
+            OutputMode::NoSound => fmod::OutputType::NoSound,
+            OutputMode::NoSoundNrt => fmod::OutputType::NoSoundNrt,
+            #[cfg(not(target_arch = "wasm32"))]
+            OutputMode::WavWriter => fmod::OutputType::WavWriter,
+            #[cfg(not(target_arch = "wasm32"))]
+            OutputMode::WavWriterNrt => fmod::OutputType::WavWriterNrt,
+        }
+    }
+}
+
+/// The speaker layout FMOD mixes for, set with [`AudioEngineConfig::speaker_mode`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum SpeakerMode {
+    /// Matches the speaker layout of the output device.
+    #[default]
+    Default,
+    /// A single speaker.
+    Mono,
+    /// Left and right speakers.
+    Stereo,
+    /// Front left, front right, surround left and surround right speakers.
+    Quad,
+    /// Quad plus a center speaker.
+    Surround,
+    /// 5.1 surround, which is surround plus a subwoofer.
+    FivePointOne,
+    /// 7.1 surround, which adds back left and back right speakers to 5.1.
+    SevenPointOne,
+    /// 7.1.4 surround, which adds four height speakers to 7.1.
+    SevenPointOneFour,
+}
+
+impl From<SpeakerMode> for fmod::SpeakerMode {
+    fn from(value: SpeakerMode) -> Self {
+        match value {
+            SpeakerMode::Default => fmod::SpeakerMode::Default,
+            SpeakerMode::Mono => fmod::SpeakerMode::Mono,
+            SpeakerMode::Stereo => fmod::SpeakerMode::Stereo,
+            SpeakerMode::Quad => fmod::SpeakerMode::Quad,
+            SpeakerMode::Surround => fmod::SpeakerMode::Surround,
+            SpeakerMode::FivePointOne => fmod::SpeakerMode::Mode5Point1,
+            SpeakerMode::SevenPointOne => fmod::SpeakerMode::Mode7Point1,
+            SpeakerMode::SevenPointOneFour => fmod::SpeakerMode::Mode7Point1Point4,
+        }
+    }
+}
+
+/// A closure which positions an event instance, attached with
+/// [`EventInstance::attach_position_source`].
+struct PositionSource {
+    instance: fmod::EventInstance,
+    source: Box<dyn FnMut() -> (Vec2, Vec2)>,
+}
+
+impl std::fmt::Debug for PositionSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PositionSource")
+            .field("instance", &self.instance)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A pitch shifter DSP added by [`EventInstance::set_pitch_shift_semitones`], which is released
+/// during [`AudioEngine::update`] once its instance is gone.
+#[derive(Debug)]
+struct PitchShifter {
+    instance: fmod::EventInstance,
+    dsp: fmod::Dsp,
+    /// The address of this is set as the user data of the DSP, which tells it apart from pitch
+    /// shifters authored in FMOD Studio.
+    tag: Box<u8>,
+}
+
+impl PitchShifter {
+    fn tag(&self) -> *mut std::ffi::c_void {
+        &*self.tag as *const u8 as *mut std::ffi::c_void
+    }
+}
+
+/// The closure set with [`AudioEngine::set_bank_callback`].
+struct BankCallback(Box<dyn FnMut(BankEvent)>);
+
+impl std::fmt::Debug for BankCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BankCallback").finish_non_exhaustive()
+    }
+}
+
+/// Something which happened to a bank, as passed to the callback set with
+/// [`AudioEngine::set_bank_callback`].
+#[derive(Debug)]
+pub enum BankEvent {
+    /// The bank was loaded, so its events can be played.
+    Loaded(BankHandle),
+    /// The bank's sample data finished loading, so its events start without delay.
+    SampleDataLoaded(BankHandle),
+    /// The bank with this GUID was unloaded. The bank can't be handed out anymore, since its
+    /// handle is invalid by now.
+    Unloaded(Guid),
+}
+
+/// Configures and initializes a freshly created studio system with the given config.
+fn initialize_studio(studio: &fmod::Studio, config: &AudioEngineConfig) -> AnyResult {
+    let mut studio_flags = fmod::StudioInit::NORMAL;
+    if config.live_update {
+        studio_flags |= fmod::StudioInit::LIVEUPDATE;
+    }
+    if config.output.is_non_realtime() {
+        // the studio update then runs on our thread, so nothing happens between updates
+        studio_flags |= fmod::StudioInit::SYNCHRONOUS_UPDATE;
+    }
+    if let Some(studio_init_flags) = config.studio_init_flags {
+        studio_flags |= studio_init_flags;
+    }
+
+    let mut flags: fmod::Init = config.handedness.into();
+    if let Some(init_flags) = config.init_flags {
+        flags |= init_flags;
+    }
+    if flags.contains(fmod::Init::THREAD_UNSAFE)
+        && !studio_flags.contains(fmod::StudioInit::SYNCHRONOUS_UPDATE)
+    {
+        return Err(eyre!(
+            "Init::THREAD_UNSAFE requires StudioInit::SYNCHRONOUS_UPDATE, as FMOD Studio's \
+             own thread would call into the core system otherwise"
+        ));
+    }
+
+    // the output and software format can only be set on the core system before initialization
+    if config.output != OutputMode::Device {
+        studio.get_core_system()?.set_output(config.output.into())?;
+    }
+    if config.sample_rate.is_some() || config.speaker_mode != SpeakerMode::Default {
+        // FMOD takes both at once, so an unconfigured sample rate is kept as it is
+        let core_system = studio.get_core_system()?;
+        let sample_rate = match config.sample_rate {
+            Some(sample_rate) => i32::try_from(sample_rate).wrap_err("sample rate is too high")?,
+            None => core_system.get_software_format()?.0,
+        };
+        core_system.set_software_format(sample_rate, config.speaker_mode.into(), 0)?;
+    }
+    if config.dsp_block_size.is_some() || config.dsp_buffer_count.is_some() {
+        // likewise, FMOD takes both at once, and its defaults differ between platforms, so
+        // whichever isn't configured is kept as it is
+        let core_system = studio.get_core_system()?;
+        let (default_block_size, default_buffer_count) = core_system.get_dsp_buffer_size()?;
+        let dsp_buffer_count = match config.dsp_buffer_count {
+            Some(dsp_buffer_count) => {
+                i32::try_from(dsp_buffer_count).wrap_err("DSP buffer count is too high")?
+            }
+            None => default_buffer_count,
+        };
+        core_system.set_dsp_buffer_size(
+            config.dsp_block_size.unwrap_or(default_block_size),
+            dsp_buffer_count,
+        )?;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(encryption_key) = &config.encryption_key {
+        // zeroes are FMOD's defaults for everything else
+        studio.set_advanced_settings(fmod::StudioAdvancedSettings {
+            commandqueuesize: 0,
+            handleinitialsize: 0,
+            studioupdateperiod: 0,
+            idlesampledatapoolsize: 0,
+            streamingscheduledelay: 0,
+            encryptionkey: encryption_key.clone(),
+        })?;
+    }
+
+    // the WAV writers take their file name as the extra driver data, which has to outlive
+    // initialization
+    #[cfg(not(target_arch = "wasm32"))]
+    let output_file = config
+        .output_file
+        .as_ref()
+        .map(|path| {
+            let path = path
+                .to_str()
+                .ok_or_else(|| eyre!("output file {} isn't valid UTF-8", path.display()))?;
+            std::ffi::CString::new(path).wrap_err("output file contains a nul byte")
+        })
+        .transpose()?;
+    #[cfg(not(target_arch = "wasm32"))]
+    let extra_driver_data = output_file
+        .as_ref()
+        .map(|path| path.as_ptr() as *mut std::ffi::c_void);
+    #[cfg(target_arch = "wasm32")]
+    let extra_driver_data = None;
+
+    studio
+        .initialize(1024, studio_flags, flags, extra_driver_data)
+        .wrap_err("failed to initialize FMOD studio")?;
+
+    Ok(())
+}
+
+/// Gets a random seed for the [`AudioEngine`]'s random number generator. If the platform can't
+/// give us one, we fall back to a fixed seed, as sound variation isn't worth failing over.
+fn random_seed() -> u64 {
+    let mut bytes = [0; 8];
+    match getrandom::getrandom(&mut bytes) {
+        Ok(()) => u64::from_le_bytes(bytes),
+        Err(_) => 0x2545F4914F6CDD1D,
+    }
+}
+
+/// Converts decibels into linear gain, with negative infinity being silence.
+fn db_to_gain(db: f32) -> f32 {
+    if db == f32::NEG_INFINITY {
+        0.0
+    } else {
+        10f32.powf(db / 20.0)
+    }
+}
+
+/// Converts linear gain into decibels, with silence being negative infinity rather than NaN.
+fn gain_to_db(gain: f32) -> f32 {
+    if gain <= 0.0 {
+        f32::NEG_INFINITY
+    } else {
+        20.0 * gain.log10()
+    }
+}
+
+/// Turns an event path like `event:/Music/Level 02` into a variant name like `MusicLevel02`.
+fn event_variant_name(event_name: &str) -> String {
+    let mut variant = String::new();
+
+    for word in event_name
+        .trim_start_matches("event:/")
+        .split(|c: char| !c.is_ascii_alphanumeric())
+    {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            variant.push(first.to_ascii_uppercase());
+            variant.extend(chars);
+        }
+    }
+
+    // identifiers can't start with a digit
+    if variant.is_empty() || variant.starts_with(|c: char| c.is_ascii_digit()) {
+        variant.insert_str(0, "Event");
+    }
+
+    variant
+}
+
+/// Gets how many bytes FMOD has allocated right now, across everything.
+#[cfg(target_arch = "wasm32")]
+fn fmod_memory_allocated() -> AnyResult<i32> {
+    Ok(fmod::memory_get_stats(true)?.0)
+}
+
+/// Gets how many bytes FMOD has allocated right now, across everything.
+#[cfg(not(target_arch = "wasm32"))]
+fn fmod_memory_allocated() -> AnyResult<i32> {
+    use libfmod::ffi;
+
+    let mut current_allocated = 0;
+    let mut max_allocated = 0;
+    // SAFETY: this only writes to the two integers.
+    let result =
+        unsafe { ffi::FMOD_Memory_GetStats(&mut current_allocated, &mut max_allocated, 1) };
+    if result != ffi::FMOD_OK {
+        return Err(eyre!("FMOD_Memory_GetStats failed with code {}", result));
+    }
+
+    Ok(current_allocated)
+}
+
+/// Builds the attributes we hand FMOD for anything positioned in 3D, mapping the position and
+/// velocity into FMOD's space. We don't track orientation, so everything faces along FMOD's `+y`
+/// with `+z` as up.
+fn attributes_3d(
+    mapping: &CoordinateMapping,
+    position: Vec3,
+    velocity: Vec3,
+) -> fmod::Attributes3d {
+    let position = mapping.map_to_fmod(position);
+    let velocity = mapping.map_to_fmod(velocity);
+
+    fmod::Attributes3d {
+        position: fmod::Vector::new(position.x, position.y, position.z),
+        velocity: fmod::Vector::new(velocity.x, velocity.y, velocity.z),
+        forward: fmod::Vector::new(0.0, 1.0, 0.0),
+        up: fmod::Vector::new(0.0, 0.0, 1.0),
+    }
+}
+
+/// Configures an [`EventInstance`] before starting it, returned by [`AudioEngine::build_event`].
+///
+/// Nothing is sent to FMOD until [`EventInstanceBuilder::start`] is called.
+#[derive(Debug)]
+pub struct EventInstanceBuilder<'a> {
+    engine: &'a AudioEngine,
+    event_name: &'a str,
+    pitch: Option<f32>,
+    volume: Option<f32>,
+    parameters: Vec<(&'a str, f32)>,
+    position: Option<Vec2>,
+}
+
+impl<'a> EventInstanceBuilder<'a> {
+    /// Sets the pitch multiplier. See [`EventInstance::set_pitch`].
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        self.pitch = Some(pitch);
+        self
+    }
+
+    /// Sets the volume. See [`EventInstance::set_volume`].
+    pub fn volume(mut self, volume: f32) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    /// Sets a parameter by case-insensitive name. Since the event hasn't started yet, this is
+    /// applied immediately, regardless of the parameter's seek speed.
+    /// See [`EventInstance::set_parameter_by_name`].
+    pub fn parameter(mut self, parameter: &'a str, value: f32) -> Self {
+        self.parameters.push((parameter, value));
+        self
+    }
+
+    /// Sets the position, with no velocity. See [`EventInstance::set_position_velocity`].
+    pub fn position(mut self, position: Vec2) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Creates the instance, applies everything that was set, then starts it.
+    ///
+    /// Like [`AudioEngine::create_event_instance`], the instance is not marked for release,
+    /// so run [`EventInstance::mark_for_release`] once you're done with it.
+    pub fn start(self) -> AnyResult<EventInstance> {
+        let event = self.engine.create_event_instance(self.event_name)?;
+
+        if let Some(pitch) = self.pitch {
+            event.set_pitch(pitch)?;
+        }
+        if let Some(volume) = self.volume {
+            event.set_volume(volume)?;
+        }
+        for (parameter, value) in self.parameters {
+            event.set_parameter_by_name(parameter, value, true)?;
+        }
+        if let Some(position) = self.position {
+            event.set_position_velocity(position, Vec2::ZERO)?;
+        }
+
+        event.start()?;
+
+        Ok(event)
+    }
+}
+
+/// An EventDescription is the authored data of an event, which every [`EventInstance`] of it
+/// shares. Get one with [`AudioEngine::event_description`].
+#[derive(Debug)]
+pub struct EventDescription(fmod::EventDescription);
+
+impl EventDescription {
+    /// Gives access to the inner [`fmod::EventDescription`].
+    pub fn inner(&self) -> &fmod::EventDescription {
+        &self.0
+    }
+
+    /// Returns the path of the event, such as `event:/Music/Level 02`.
+    pub fn path(&self) -> AnyResult<String> {
+        Ok(self.0.get_path()?)
+    }
+
+    /// Checks whether the event is 3D, meaning it has a spatializer or otherwise reacts to
+    /// positions.
+    pub fn is_3d(&self) -> AnyResult<bool> {
+        Ok(self.0.is_3d()?)
+    }
+
+    /// Checks whether the event is a one-shot, meaning it stops on its own rather than looping
+    /// or waiting on a sustain point.
+    pub fn is_oneshot(&self) -> AnyResult<bool> {
+        Ok(self.0.is_oneshot()?)
+    }
+
+    /// Lists the parameters of the event, such as to build a slider for each of them in an
+    /// editor.
+    pub fn parameters(&self) -> AnyResult<Vec<ParameterDescription>> {
+        let count = self.0.get_parameter_description_count()?;
+
+        (0..count)
+            .map(|index| Ok(self.0.get_parameter_description_by_index(index)?.into()))
+            .collect()
+    }
+
+    /// Looks up a user property of the event, which sound designers attach in FMOD Studio to
+    /// pass along data such as subtitles or gameplay tags.
+    pub fn user_property(&self, name: &str) -> AnyResult<UserProperty> {
+        let (_, value) = UserProperty::from_fmod(self.0.get_user_property(name)?)?;
+
+        Ok(value)
+    }
+
+    /// Lists the user properties of the event by name.
+    pub fn user_properties(&self) -> AnyResult<Vec<(String, UserProperty)>> {
+        let count = self.0.get_user_property_count()?;
+
+        (0..count)
+            .map(|index| UserProperty::from_fmod(self.0.get_user_property_by_index(index)?))
+            .collect()
+    }
+
+    /// Retrieves the minimum and maximum distance of the event, as authored in FMOD Studio.
+    /// Past the maximum distance, the event can't be heard, so it's the one to cull by.
+    pub fn min_max_distance(&self) -> AnyResult<(f32, f32)> {
+        Ok(self.0.get_min_max_distance()?)
+    }
+
+    /// Checks that the description still refers to a loaded event. Descriptions become invalid
+    /// once the bank holding them is unloaded, such as by [`AudioEngine::unload_banks`], after
+    /// which every other call on them returns an error.
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+}
+
+/// An EventInstance is a *particular* event being fired, which can be configured
+/// with various effects and parameters.
+///
+/// We have not bound everything that FMod offers, so to get to the underlying functions,
+/// you can run [`EventInstance::inner`].
+#[derive(Debug)]
+pub struct EventInstance(fmod::EventInstance, CoordinateMapping);
+
+impl EventInstance {
+    /// Gives access to the inner [`fmod::EventInstance`].
+    /// This is a kind of get-out-of-jail-free card, since we haven't fully
+    /// bound the entire FMOD API ourselves yet, so you might need something here.
+    pub fn inner(&self) -> &fmod::EventInstance {
+        &self.0
+    }
+
+    /// Actually starts playing the audio. If the instance was already playing, this will restart playback.
+    pub fn start(&self) -> AnyResult {
+        Ok(self.0.start()?)
+    }
+
+    /// Starts the instance already paused, so it's ready to go but makes no sound until
+    /// [`EventInstance::unpause`] is called. FMOD runs commands in the order they were sent,
+    /// so unlike calling [`EventInstance::start`] first, no audio slips out in between.
+    pub fn start_paused(&self) -> AnyResult {
+        self.pause()?;
+        self.start()
+    }
+
+    /// Marks the event instance for release.
+    ///
+    /// Event instances marked for release are destroyed when they are in the stopped
+    /// state [`PlaybackState::Stopped]`.
+    pub fn mark_for_release(&self) -> AnyResult {
+        Ok(self.0.release()?)
+    }
+
+    /// Sets the pitch of the audio.
+    /// The pitch multiplier is used to modulate the event instance's pitch.
+    /// The pitch multiplier can be set to any value greater than or equal to zero but
+    /// the final combined pitch is clamped to the range [0, 100] before being applied.
+    ///
+    /// The default pitch is `1.0`.
+    ///
+    /// ## Panics
+    ///
+    /// In `debug`, we panic if `pitch < 0.0`.
+    pub fn set_pitch(&self, pitch: f32) -> AnyResult {
+        debug_assert!(pitch >= 0.0);
+
+        self.0.set_pitch(pitch)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the pitch multiplier. See [`EventInstance::final_pitch`] to get the final
+    /// pitch after any modulation or changes.
+    pub fn pitch(&self) -> AnyResult<f32> {
+        Ok(self.0.get_pitch()?.0)
+    }
+
+    /// Sets the pitch in semitones rather than as a multiplier, so `12.0` is an octave up and
+    /// `-12.0` an octave down. Like [`EventInstance::set_pitch`], this changes speed too, see
+    /// [`EventInstance::set_pitch_shift_semitones`] otherwise.
+    ///
+    /// The multiplier this turns into, `2^(semitones / 12)`, is always positive, so this never
+    /// trips the debug assertion in [`EventInstance::set_pitch`].
+    pub fn set_pitch_semitones(&self, semitones: f32) -> AnyResult {
+        self.set_pitch(2f32.powf(semitones / 12.0))
+    }
+
+    /// Retrieves the pitch in semitones, as set with [`EventInstance::set_pitch_semitones`].
+    /// A pitch multiplier of `0.0` comes back as negative infinity.
+    pub fn pitch_semitones(&self) -> AnyResult<f32> {
+        Ok(12.0 * self.pitch()?.log2())
+    }
+
+    /// Retrieves the final pitch multiplier. The final combined value returned combines the pitch set
+    /// using [`EventInstance::set_pitch`] with the result of any automation or modulation.
+    /// The final combined pitch is calculated asynchronously once a frame.
+    ///
+    /// See [`EventInstance::pitch`] to get the pitch on this event alone.
+    pub fn final_pitch(&self) -> AnyResult<f32> {
+        Ok(self.0.get_pitch()?.1)
+    }
+
+    /// Sets the value of a built-in property.
+    pub fn set_property(&self, property: EventProperty, value: f32) -> AnyResult {
+        self.0.set_property(property.into(), value)?;
+        Ok(())
+    }
+    /// Gets the value of a built-in property.
+    pub fn property(&self, property: EventProperty) -> AnyResult<f32> {
+        Ok(self.0.get_property(property.into())?)
+    }
+
+    /// Sets the priority of the instance's channels, from `0` (most important) to `256` (least
+    /// important), or `-1` to use the priority set in FMOD Studio. When FMOD runs out of
+    /// channels, the least important ones are virtualized first.
+    ///
+    /// This is [`EventProperty::ChannelPriority`] under the hood.
+    ///
+    /// ## Panics
+    ///
+    /// In `debug`, we panic if `priority` is outside of `-1..=256`.
+    pub fn set_priority(&self, priority: i32) -> AnyResult {
+        debug_assert!(
+            (-1..=256).contains(&priority),
+            "channel priority must be -1 or in 0..=256, got {}",
+            priority
+        );
+
+        self.set_property(EventProperty::ChannelPriority, priority as f32)
+    }
+
+    /// Gets the priority of the instance's channels, or `-1` if the priority set in FMOD Studio
+    /// is used. See [`EventInstance::set_priority`].
+    pub fn priority(&self) -> AnyResult<i32> {
+        Ok(self.property(EventProperty::ChannelPriority)? as i32)
+    }
+
+    /// Sets every property which is `Some` in `properties`, leaving the rest as they are.
+    pub fn set_properties(&self, properties: &EventProperties) -> AnyResult {
+        for (property, value) in properties.fields() {
+            if let Some(value) = value {
+                self.set_property(property, value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets every built-in property at once. Properties which aren't overridden, and so use the
+    /// event's default, are `None`.
+    pub fn properties(&self) -> AnyResult<EventProperties> {
+        let property = |property| -> AnyResult<Option<f32>> {
+            let value = self.property(property)?;
+            Ok((value != -1.0).then_some(value))
+        };
+
+        Ok(EventProperties {
+            channel_priority: property(EventProperty::ChannelPriority)?,
+            schedule_delay: property(EventProperty::ScheduleDelay)?,
+            schedule_lookahead: property(EventProperty::ScheduleLookahead)?,
+            minimum_distance: property(EventProperty::MinimumDistance)?,
+            maximum_distance: property(EventProperty::MaximumDistance)?,
+            cooldown: property(EventProperty::Cooldown)?,
+        })
+    }
+
+    /// Sets the timeline cursor position.
+    ///
+    /// The units are in *milliseconds* and has the maximum size of `i32::MAX` (not `u32`).
+    pub fn set_timeline_position(&self, timeline_position: u32) -> AnyResult {
+        self.0.set_timeline_position(timeline_position as i32)?;
+
+        Ok(())
+    }
+
+    /// Gets the timeline cursor position.
+    pub fn timeline_position(&self) -> AnyResult<u32> {
+        Ok(self.0.get_timeline_position()? as u32)
+    }
+
+    /// Sets the volume level.
+    /// This volume is applied as a scaling factor for the event volume.
+    /// It does not override the volume level set in FMOD Studio, nor any internal volume automation or modulation.
+    pub fn set_volume(&self, volume: f32) -> AnyResult {
+        self.0.set_volume(volume)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the volume level. See [`EventInstance::final_volume`] to get the final
+    /// volume after any modulation or changes.
+    pub fn volume(&self) -> AnyResult<f32> {
+        Ok(self.0.get_volume()?.0)
+    }
+
+    /// Sets the volume level in decibels rather than as linear gain, so `0.0` leaves the volume
+    /// as is and `-6.0` roughly halves it. `f32::NEG_INFINITY` is silence.
+    pub fn set_volume_db(&self, db: f32) -> AnyResult {
+        self.set_volume(db_to_gain(db))
+    }
+
+    /// Retrieves the volume level in decibels, as set with [`EventInstance::set_volume_db`].
+    /// Silence comes back as `f32::NEG_INFINITY`.
+    pub fn volume_db(&self) -> AnyResult<f32> {
+        Ok(gain_to_db(self.volume()?))
+    }
+
+    /// Retrieves the final volume multiplier. The final combined value returned combines the volume set
+    /// using [`EventInstance::set_volume`] with the result of any automation or modulation.
+    /// The final combined volume is calculated asynchronously once a frame.
+    ///
+    /// See [`EventInstance::volume`] to get the volume on this event alone.
+    pub fn final_volume(&self) -> AnyResult<f32> {
+        Ok(self.0.get_volume()?.1)
+    }
+
+    /// Retrieves the virtualization state.
+    ///
+    /// This function checks whether an event instance has been virtualized due to the polyphony limit
+    /// being exceeded.
+    pub fn is_virtual(&self) -> AnyResult<bool> {
+        Ok(self.0.is_virtual()?)
+    }
+
+    /// Gets how much memory the instance uses, such as to find out which events are heavy when
+    /// chasing down memory use.
+    ///
+    /// FMOD only tracks this in its logging builds, so with the release libraries, every field
+    /// is `0`.
+    pub fn memory_usage(&self) -> AnyResult<MemoryUsage> {
+        Ok(self.0.get_memory_usage()?.into())
+    }
+
+    /// Sets the position and velocity on this event instance.
+    pub fn set_position_velocity(&self, position: Vec2, velocity: Vec2) -> AnyResult {
+        self.set_position_velocity_3d(position.extend(0.0), velocity.extend(0.0))
+    }
+
+    /// Sets the position and velocity on this event instance, including elevation.
+    /// [`EventInstance::set_position_velocity`] is the same as this with `z` set to `0.0`.
+    pub fn set_position_velocity_3d(&self, position: Vec3, velocity: Vec3) -> AnyResult {
+        self.0
+            .set_3d_attributes(attributes_3d(&self.1, position, velocity))?;
+
+        Ok(())
+    }
+
+    /// Makes this instance follow the position and velocity returned by `source`, which `engine`
+    /// calls during every [`AudioEngine::update`], just before FMOD updates. This turns following
+    /// a moving object into a single call.
+    ///
+    /// Once the instance becomes invalid, such as after being released and stopped, `source` is
+    /// dropped. Attaching another source to the same instance doesn't replace the first one, so
+    /// both would run.
+    pub fn attach_position_source(
+        &self,
+        engine: &AudioEngine,
+        source: impl FnMut() -> (Vec2, Vec2) + 'static,
+    ) {
+        engine.position_sources.borrow_mut().push(PositionSource {
+            instance: self.0.clone(),
+            source: Box::new(source),
+        });
+    }
+
+    /// Gets the position and velocity on this event instance.
+    pub fn get_position_velocity(&self) -> AnyResult<AudioPositionVelocity> {
+        let AudioPositionVelocity3d { position, velocity } = self.get_position_velocity_3d()?;
+
+        Ok(AudioPositionVelocity {
+            position: position.truncate(),
+            velocity: velocity.truncate(),
+        })
+    }
+
+    /// Gets the position and velocity on this event instance, including elevation.
+    pub fn get_position_velocity_3d(&self) -> AnyResult<AudioPositionVelocity3d> {
+        let atty = self.0.get_3d_attributes()?;
+
+        Ok(AudioPositionVelocity3d {
+            position: self.1.map_from_fmod(Vec3::new(
+                atty.position.x,
+                atty.position.y,
+                atty.position.z,
+            )),
+            velocity: self.1.map_from_fmod(Vec3::new(
+                atty.velocity.x,
+                atty.velocity.y,
+                atty.velocity.z,
+            )),
+        })
+    }
+
+    /// Sets a given parameter by case-insensitive name.
+    ///
+    /// `ignore_seek_speed` specifies whether to ignore the parameter's seek speed and set the value immediately
+    /// The `value` will be set instantly regardless of `ignore_seek_speed` when the Event playback is
+    /// [`PlaybackState::Stopped`].
+    ///
+    /// If the specified parameter is an automatic parameter then an error is returned. If the event has no parameter
+    /// matching name then an error is returned.
+    pub fn set_parameter_by_name(
+        &self,
+        parameter: &str,
+        value: f32,
+        ignore_seek_speed: bool,
+    ) -> AnyResult {
+        self.0
+            .set_parameter_by_name(parameter, value, ignore_seek_speed)?;
+
+        Ok(())
+    }
+
+    /// Retrieves a parameter value by case-insensitive name. See [`EventInstance::get_final_parameter_by_name`] as well.
+    ///
+    /// Automatic parameters always return value as 0 since they can never have their value set from the public API.
+    pub fn get_parameter_by_name(&self, parameter: &str) -> AnyResult<f32> {
+        Ok(self.0.get_parameter_by_name(parameter)?.0)
+    }
+
+    /// Retrieves a parameter's final value by case-insensitive name. This is the final value of the parameter after
+    /// applying adjustments due to automation, modulation, seek speed, and parameter velocity to value. This is
+    /// calculated asynchronously once a frame.
+    ///
+    /// Automatic parameters always return value as 0 since they can never have their value set from the public API.
+    ///
+    /// See [`EventInstance::get_parameter_by_name`] for the value without other adjustments.
+    pub fn get_final_parameter_by_name(&self, parameter: &str) -> AnyResult<f32> {
+        Ok(self.0.get_parameter_by_name(parameter)?.1)
+    }
+
+    /// Stops playback with the given [`StopMode`]. [`StopMode::AllowFadeout`] allows AHDSR
+    /// modulators to complete their release, and DSP effect tails to play out, which is the
+    /// preferred way to stop audio.
+    pub fn stop(&self, mode: StopMode) -> AnyResult {
+        self.0.stop(mode.into())?;
+
+        Ok(())
+    }
+
+    /// Stops playback immediately. This is the same as [`EventInstance::stop`] with
+    /// [`StopMode::Immediate`].
+    pub fn stop_immediately(&self) -> AnyResult {
+        self.stop(StopMode::Immediate)
+    }
+
+    /// Checks whether the instance has fully stopped, including any fadeout, such as to wait
+    /// before unloading banks. See [`EventInstance::playback_state`].
+    pub fn has_stopped(&self) -> AnyResult<bool> {
+        Ok(self.playback_state()? == PlaybackState::Stopped)
+    }
+
+    /// Stops the instance like [`EventInstance::stop`], and returns a future which resolves once
+    /// it has fully stopped, including any fadeout.
+    ///
+    /// The playback state only changes during [`AudioEngine::update`], so the future checks it
+    /// again after each update of `engine`, which is when it gets woken. Keep updating the
+    /// engine while waiting, or this never resolves.
+    ///
+    /// Only available with the `async` feature.
+    #[cfg(feature = "async")]
+    pub fn stop_and_wait<'a>(
+        &'a self,
+        engine: &'a AudioEngine,
+        mode: StopMode,
+    ) -> impl Future<Output = AnyResult> + 'a {
+        let stopped = self.stop(mode);
+
+        async move {
+            stopped?;
+
+            engine.wait_for_updates(|| self.has_stopped()).await
+        }
+    }
+
+    /// Pauses the given event. If the event is already paused, this doesn't do anything.
+    pub fn pause(&self) -> AnyResult {
+        self.0.set_paused(true)?;
+        Ok(())
+    }
+
+    /// Unpauses the given event. If the event isn't paused, this doesn't do anything.
+    pub fn unpause(&self) -> AnyResult {
+        self.0.set_paused(false)?;
+        Ok(())
+    }
+
+    /// Returns the pause state of the event. Note that this is different from [`EventInstance::playback_state`].
+    pub fn is_paused(&self) -> AnyResult<bool> {
+        Ok(self.0.get_paused()?)
+    }
+
+    /// You can poll this function to track the playback state of an event instance.
+    ///
+    /// If the instance is invalid, then the state will be set to [`PlaybackState::Stopped`].
+    ///
+    /// Note that the playback state can be [`PlaybackState::Playing`] while [`EventInstance::is_paused`] also
+    /// return true! In a sense, this is mostly "lifetime" state, rather than just a playback state.
+    pub fn playback_state(&self) -> AnyResult<PlaybackState> {
+        let state = self.0.get_playback_state()?;
+
+        Ok(state.into())
+    }
+
+    /// Releases the sustain point the timeline cursor is currently held on, letting the timeline
+    /// continue. This is how interactive music moves on from a region that loops until gameplay
+    /// says otherwise.
+    ///
+    /// While held on a sustain point, [`EventInstance::playback_state`] is
+    /// [`PlaybackState::Sustaining`]. If the cursor isn't on a sustain point, this releases the
+    /// next one it reaches instead.
+    pub fn key_off(&self) -> AnyResult {
+        self.0.key_off()?;
+
+        Ok(())
+    }
+
+    /// Sets which listeners hear this instance, where bit `N` enables listener `N`. For example,
+    /// `0b01` makes a UI sound only reach player 1's listener in split-screen. See
+    /// [`AudioEngine::set_listener_count`].
+    ///
+    /// The default is `0xFFFF_FFFF`, so every listener hears the instance. A mask without any of
+    /// the existing listeners in it mutes the instance.
+    pub fn set_listener_mask(&self, mask: u32) -> AnyResult {
+        self.0.set_listener_mask(mask)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the mask set with [`EventInstance::set_listener_mask`].
+    pub fn listener_mask(&self) -> AnyResult<u32> {
+        Ok(self.0.get_listener_mask()?)
+    }
+
+    /// Checks that the instance still refers to a live FMOD event instance.
+    ///
+    /// Instances marked for release with [`EventInstance::mark_for_release`] become invalid
+    /// once they are stopped, after which every other call on them returns an error.
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    /// Makes this instance directional, like a loudspeaker, by setting the 3D cone on its
+    /// channel group. Angles are in degrees.
+    ///
+    /// Within `inside_angle` the volume is unchanged, outside of `outside_angle` it is scaled by
+    /// `outside_volume`, and in between it is interpolated. The cone points along the instance's
+    /// forward vector, which is `+y` for everything positioned through this crate.
+    ///
+    /// The channel group only exists once the instance has been started and FMOD has updated,
+    /// so until then this returns an error.
+    ///
+    /// ## Panics
+    ///
+    /// In `debug`, we panic unless `0 <= inside_angle <= outside_angle <= 360`.
+    pub fn set_cone(
+        &self,
+        inside_angle: f32,
+        outside_angle: f32,
+        outside_volume: f32,
+    ) -> AnyResult {
+        debug_assert!(
+            (0.0..=outside_angle).contains(&inside_angle) && outside_angle <= 360.0,
+            "cone angles must satisfy 0 <= inside <= outside <= 360, got {} and {}",
+            inside_angle,
+            outside_angle
+        );
+
+        self.0.get_channel_group()?.set_3d_cone_settings(
+            inside_angle,
+            outside_angle,
+            outside_volume,
+        )?;
+
+        Ok(())
+    }
+
+    /// Occludes this instance, such as when it's behind a wall. `direct` muffles the sound
+    /// itself, while `reverb` reduces how much of it reaches reverb. Both go from `0.0`, not
+    /// occluded, to `1.0`, fully occluded.
+    ///
+    /// Like [`EventInstance::set_cone`], this goes through the instance's channel group, so it
+    /// returns an error until the instance has been started and FMOD has updated.
+    ///
+    /// ## Panics
+    ///
+    /// In `debug`, we panic if either value is outside of `[0.0, 1.0]`.
+    pub fn set_occlusion(&self, direct: f32, reverb: f32) -> AnyResult {
         debug_assert!(
-            event_name.starts_with("event:/"),
-            "all fmod events begin with `event:/`, this event is only {}",
-            event_name
+            (0.0..=1.0).contains(&direct) && (0.0..=1.0).contains(&reverb),
+            "occlusion must be within [0, 1], got {} and {}",
+            direct,
+            reverb
         );
 
-        event_name
+        self.0
+            .get_channel_group()?
+            .set_3d_occlusion(direct, reverb)?;
+
+        Ok(())
+    }
+
+    /// Shifts the pitch of this instance by `semitones` without changing its speed, such as
+    /// to speed up a voice line without it sounding like a chipmunk. Positive values shift up and
+    /// negative values shift down, by up to an octave either way.
+    ///
+    /// Unlike [`EventInstance::set_pitch`], which resamples, this adds FMOD's pitch shifter DSP
+    /// to the instance's channel group, and that DSP is expensive: each shifted instance costs
+    /// about as much CPU as a reverb. Setting `0.0` removes the DSP again. Otherwise, `engine`
+    /// keeps track of the DSP, and releases it during [`AudioEngine::update`] once the instance
+    /// is released and gone. Pitch shifters authored in FMOD Studio are left alone.
+    ///
+    /// Like [`EventInstance::set_cone`], this goes through the instance's channel group, so it
+    /// returns an error until the instance has been started and FMOD has updated.
+    pub fn set_pitch_shift_semitones(&self, engine: &AudioEngine, semitones: f32) -> AnyResult {
+        let channel_group = self.0.get_channel_group()?;
+        let mut pitch_shifters = engine.pitch_shifters.borrow_mut();
+        // FMOD doesn't hand out the number of DSPs the same way on both backends, so we walk
+        // them until we run out, looking for the one we tagged
+        let existing = (0..)
+            .map_while(|index| channel_group.get_dsp(index).ok())
+            .find_map(|dsp| {
+                let user_data = dsp.get_user_data().ok()?;
+                pitch_shifters
+                    .iter()
+                    .position(|pitch_shifter| pitch_shifter.tag() == user_data)
+            });
+
+        if semitones == 0.0 {
+            if let Some(index) = existing {
+                let pitch_shifter = pitch_shifters.swap_remove(index);
+                channel_group.remove_dsp(pitch_shifter.dsp.clone())?;
+                pitch_shifter.dsp.release()?;
+            }
+
+            return Ok(());
+        }
+
+        let dsp = match existing {
+            Some(index) => pitch_shifters[index].dsp.clone(),
+            None => {
+                let pitch_shifter = PitchShifter {
+                    instance: self.0.clone(),
+                    dsp: channel_group
+                        .get_system_object()?
+                        .create_dsp_by_type(fmod::DspType::PitchShift)?,
+                    tag: Box::new(0),
+                };
+                let added = pitch_shifter
+                    .dsp
+                    .set_user_data(pitch_shifter.tag())
+                    .and_then(|()| {
+                        channel_group.add_dsp(CHANNEL_CONTROL_DSP_HEAD, pitch_shifter.dsp.clone())
+                    });
+                if let Err(err) = added {
+                    pitch_shifter.dsp.release().ok();
+                    return Err(err.into());
+                }
+
+                let dsp = pitch_shifter.dsp.clone();
+                pitch_shifters.push(pitch_shifter);
+                dsp
+            }
+        };
+        dsp.set_parameter_float(DSP_PITCHSHIFT_PITCH, 2f32.powf(semitones / 12.0))?;
+
+        Ok(())
+    }
+
+    /// Associates a value with this instance, such as the id of the entity which played it, so
+    /// code which only has the instance can find out what it belongs to. Get it back with
+    /// [`EventInstance::user_data`].
+    ///
+    /// FMOD stores this as a pointer-sized value, so on wasm, where pointers are 32 bits, an error
+    /// is returned if `data` doesn't fit in a `u32`.
+    pub fn set_user_data(&self, data: u64) -> AnyResult {
+        let data = usize::try_from(data).map_err(|_| {
+            eyre!(
+                "user data {} doesn't fit in a pointer on this platform",
+                data
+            )
+        })?;
+        self.0.set_user_data(data as *mut std::ffi::c_void)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the value set with [`EventInstance::set_user_data`], or `0` if none was set.
+    pub fn user_data(&self) -> AnyResult<u64> {
+        Ok(self.0.get_user_data()? as usize as u64)
+    }
+
+    /// Turns this instance into a guard which stops it with a fadeout when dropped, which ties a
+    /// looping event to a scope, such as playing an ambience only while a menu is open.
+    pub fn into_guard(self) -> EventInstanceGuard {
+        EventInstanceGuard(self)
+    }
+
+    /// Returns the path of the event this instance was created from, such as `event:/Music/Level 02`.
+    /// This is mostly useful for logging.
+    ///
+    /// The path is only available if the strings bank is loaded, otherwise an error is returned.
+    pub fn event_path(&self) -> AnyResult<String> {
+        Ok(self.0.get_description()?.get_path()?)
     }
 }
 
-/// An EventInstance is a *particular* event being fired, which can be configured
-/// with various effects and parameters.
+/// Stops the [`EventInstance`] it holds with [`EventInstance::stop`] when dropped. Make one with
+/// [`EventInstance::into_guard`].
 ///
-/// We have not bound everything that FMod offers, so to get to the underlying functions,
-/// you can run [`EventInstance::inner`].
+/// This derefs to the [`EventInstance`], so it can still be changed while it plays.
 #[derive(Debug)]
-pub struct EventInstance(fmod::EventInstance);
+pub struct EventInstanceGuard(EventInstance);
 
-impl EventInstance {
-    /// Gives access to the inner [`fmod::EventInstance`].
-    /// This is a kind of get-out-of-jail-free card, since we haven't fully
-    /// bound the entire FMOD API ourselves yet, so you might need something here.
-    pub fn inner(&self) -> &fmod::EventInstance {
+impl Deref for EventInstanceGuard {
+    type Target = EventInstance;
+
+    fn deref(&self) -> &Self::Target {
         &self.0
     }
+}
 
-    /// Actually starts playing the audio. If the instance was already playing, this will restart playback.
-    pub fn start(&self) -> AnyResult {
-        Ok(self.0.start()?)
+impl Drop for EventInstanceGuard {
+    fn drop(&mut self) {
+        // the instance may already be released and gone, which is fine
+        self.0.stop(StopMode::AllowFadeout).ok();
     }
+}
 
-    /// Marks the event instance for release.
+/// A bank holds the events and sample data built in FMOD Studio. Get them with
+/// [`AudioEngine::banks`].
+#[derive(Debug)]
+pub struct BankHandle(fmod::Bank);
+
+impl BankHandle {
+    /// Gives access to the inner [`fmod::Bank`].
+    pub fn inner(&self) -> &fmod::Bank {
+        &self.0
+    }
+
+    /// Retrieves the loading state of the bank's metadata, such as its events. Once this is
+    /// [`LoadingState::Loaded`], events can be created, though their samples may still be
+    /// loading. See [`BankHandle::sample_loading_state`].
+    pub fn loading_state(&self) -> AnyResult<LoadingState> {
+        Ok(self.0.get_loading_state()?.into())
+    }
+
+    /// Retrieves the loading state of the bank's sample data. Events can play before this is
+    /// [`LoadingState::Loaded`], but their sounds may start late.
+    pub fn sample_loading_state(&self) -> AnyResult<LoadingState> {
+        Ok(self.0.get_sample_loading_state()?.into())
+    }
+
+    /// Returns the GUID of the bank, which stays the same across builds of the project.
+    pub fn id(&self) -> AnyResult<Guid> {
+        Ok(self.0.get_id()?.into())
+    }
+
+    /// Returns the path of the bank, such as `bank:/Music`. This needs the strings bank to be
+    /// loaded.
+    pub fn path(&self) -> AnyResult<String> {
+        Ok(self.0.get_path()?)
+    }
+
+    /// Checks that the bank is still loaded. Banks become invalid once they're unloaded, such
+    /// as by [`AudioEngine::unload_banks`], after which every other call on them returns an
+    /// error.
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+}
+
+/// A bus routes the output of events and other buses, and can be used to control many events at
+/// once, such as everything under `bus:/Ambience`. Get one with [`AudioEngine::bus`].
+#[derive(Debug)]
+pub struct BusHandle(fmod::Bus);
+
+impl BusHandle {
+    /// Gives access to the inner [`fmod::Bus`].
+    pub fn inner(&self) -> &fmod::Bus {
+        &self.0
+    }
+
+    /// Mutes or unmutes the bus. Muting a bus mutes everything routed into it.
     ///
-    /// Event instances marked for release are destroyed when they are in the stopped
-    /// state [`PlaybackState::Stopped]`.
-    pub fn mark_for_release(&self) -> AnyResult {
-        Ok(self.0.release()?)
+    /// There's no solo to go with this: solo only exists in FMOD Studio's mixer, and the runtime
+    /// API has no `Studio_Bus_SetSolo` for us to bind. To hear a bus in isolation, mute its
+    /// siblings instead, keeping in mind that muting a parent bus also mutes the bus itself.
+    pub fn set_mute(&self, mute: bool) -> AnyResult {
+        self.0.set_mute(mute)?;
+
+        Ok(())
+    }
+
+    /// Returns the mute state of the bus.
+    pub fn is_muted(&self) -> AnyResult<bool> {
+        Ok(self.0.get_mute()?)
+    }
+
+    /// Sets the volume level.
+    /// This volume is applied as a scaling factor to the volume level set in FMOD Studio.
+    pub fn set_volume(&self, volume: f32) -> AnyResult {
+        self.0.set_volume(volume)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the volume level. See [`BusHandle::final_volume`] to get the final
+    /// volume after any modulation or changes.
+    pub fn volume(&self) -> AnyResult<f32> {
+        Ok(self.0.get_volume()?.0)
+    }
+
+    /// Sets the volume level in decibels. See [`EventInstance::set_volume_db`].
+    pub fn set_volume_db(&self, db: f32) -> AnyResult {
+        self.set_volume(db_to_gain(db))
+    }
+
+    /// Retrieves the volume level in decibels. See [`EventInstance::volume_db`].
+    pub fn volume_db(&self) -> AnyResult<f32> {
+        Ok(gain_to_db(self.volume()?))
+    }
+
+    /// Retrieves the final volume level. The final combined value returned combines the volume set
+    /// using [`BusHandle::set_volume`] with the result of any automation or modulation.
+    /// The final combined volume is calculated asynchronously once a frame.
+    pub fn final_volume(&self) -> AnyResult<f32> {
+        Ok(self.0.get_volume()?.1)
+    }
+
+    /// Pauses or unpauses the bus. Pausing a bus pauses everything routed into it.
+    pub fn set_paused(&self, paused: bool) -> AnyResult {
+        self.0.set_paused(paused)?;
+
+        Ok(())
+    }
+
+    /// Returns the pause state of the bus.
+    pub fn is_paused(&self) -> AnyResult<bool> {
+        Ok(self.0.get_paused()?)
+    }
+
+    /// Stops every event instance routed into this bus, such as everything under
+    /// `bus:/Ambience` when transitioning scenes. See [`StopMode`].
+    pub fn stop_all_events(&self, mode: StopMode) -> AnyResult {
+        self.0.stop_all_events(mode.into())?;
+
+        Ok(())
+    }
+
+    /// Keeps the bus's channel group alive and turns on metering for its output, so its levels
+    /// can be read with [`BusHandle::peak_levels`] even when nothing is playing through it.
+    ///
+    /// Metering isn't free, so it's off until this is called. FMOD only creates the channel
+    /// group when it runs its commands, so this flushes them with `engine`, as described in
+    /// [`AudioEngine::flush_commands`]. Levels are measured from the next mix on.
+    pub fn enable_metering(&self, engine: &AudioEngine) -> AnyResult {
+        self.0.lock_channel_group()?;
+        engine.flush_commands()?;
+
+        self.0
+            .get_channel_group()?
+            .get_dsp(CHANNEL_CONTROL_DSP_HEAD)?
+            .set_metering_enabled(false, true)?;
+
+        Ok(())
+    }
+
+    /// Returns the left and right peak levels of the bus's output, as linear gain. Mono
+    /// output returns the same level for both.
+    ///
+    /// This requires [`BusHandle::enable_metering`] to have been called first.
+    pub fn peak_levels(&self) -> AnyResult<(f32, f32)> {
+        let dsp = self
+            .0
+            .get_channel_group()?
+            .get_dsp(CHANNEL_CONTROL_DSP_HEAD)?;
+
+        let (_, output) = dsp.get_metering_info()?;
+        let left = output.peaklevel[0];
+        let right = if output.numchannels > 1 {
+            output.peaklevel[1]
+        } else {
+            left
+        };
+
+        Ok((left, right))
+    }
+}
+
+/// The core FMOD System, which the studio system is built on top of. Get it
+/// with [`AudioEngine::core_system`].
+#[derive(Debug)]
+pub struct CoreSystem(fmod::System);
+
+impl CoreSystem {
+    /// Gives access to the inner [`fmod::System`].
+    pub fn inner(&self) -> &fmod::System {
+        &self.0
+    }
+
+    /// Retrieves the number of output drivers (devices) available, such as speakers and headsets.
+    ///
+    /// Only available on native, since on the web the browser picks the output device.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn num_drivers(&self) -> AnyResult<u32> {
+        Ok(self.0.get_num_drivers()? as u32)
     }
 
-    /// Sets the pitch of the audio.
-    /// The pitch multiplier is used to modulate the event instance's pitch.
-    /// The pitch multiplier can be set to any value greater than or equal to zero but
-    /// the final combined pitch is clamped to the range [0, 100] before being applied.
-    ///
-    /// The default pitch is `1.0`.
-    ///
-    /// ## Panics
+    /// Retrieves information about the output driver at `index`, which must be
+    /// less than [`CoreSystem::num_drivers`].
     ///
-    /// In `debug`, we panic if `pitch < 0.0`.
-    pub fn set_pitch(&self, pitch: f32) -> AnyResult {
-        debug_assert!(pitch >= 0.0);
+    /// Only available on native, since on the web the browser picks the output device.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn driver_info(&self, index: u32) -> AnyResult<DriverInfo> {
+        let (name, _, system_rate, _, speaker_mode_channels) =
+            self.0.get_driver_info(index as i32, 256)?;
 
-        self.0.set_pitch(pitch)?;
+        Ok(DriverInfo {
+            name,
+            system_rate,
+            speaker_mode_channels,
+        })
+    }
+
+    /// Sets the output driver at `index` as the one to play audio through. This can be
+    /// called at runtime, and FMOD will move playback over to the new device.
+    ///
+    /// Only available on native, since on the web the browser picks the output device.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_driver(&self, index: u32) -> AnyResult {
+        self.0.set_driver(index as i32)?;
 
         Ok(())
     }
 
-    /// Retrieves the pitch multiplier. See [`EventInstance::final_pitch`] to get the final
-    /// pitch after any modulation or changes.
-    pub fn pitch(&self) -> AnyResult<f32> {
-        Ok(self.0.get_pitch()?.0)
-    }
+    /// Suspends the mixer thread and relinquishes usage of the audio device.
+    /// See [`AudioEngine::suspend_mixer`].
+    pub fn mixer_suspend(&self) -> AnyResult {
+        self.0.mixer_suspend()?;
 
-    /// Retrieves the final pitch multiplier. The final combined value returned combines the pitch set
-    /// using [`EventInstance::set_pitch`] with the result of any automation or modulation.
-    /// The final combined pitch is calculated asynchronously once a frame.
-    ///
-    /// See [`EventInstance::pitch`] to get the pitch on this event alone.
-    pub fn final_pitch(&self) -> AnyResult<f32> {
-        Ok(self.0.get_pitch()?.1)
+        Ok(())
     }
 
-    /// Sets the value of a built-in property.
-    pub fn set_property(&self, property: EventProperty, value: f32) -> AnyResult {
-        self.0.set_property(property.into(), value)?;
+    /// Resumes the mixer thread and reacquires the audio device.
+    /// See [`AudioEngine::resume_mixer`].
+    pub fn mixer_resume(&self) -> AnyResult {
+        self.0.mixer_resume()?;
+
         Ok(())
     }
-    /// Gets the value of a built-in property.
-    pub fn property(&self, property: EventProperty) -> AnyResult<f32> {
-        Ok(self.0.get_property(property.into())?)
+
+    /// Creates one of FMOD's built-in DSPs, which does nothing until it's added to a channel
+    /// group with [`ChannelGroup::add_dsp`].
+    pub fn create_dsp_by_type(&self, dsp_type: DspType) -> AnyResult<Dsp> {
+        Ok(Dsp(self.0.create_dsp_by_type(dsp_type.into())?))
     }
 
-    /// Sets the timeline cursor position.
+    /// Creates a sound out of an encoded file in memory, such as a WAV or Ogg Vorbis file, for
+    /// sounds which don't live in a bank. FMOD copies the data, so it can be dropped afterwards.
     ///
-    /// The units are in *milliseconds* and has the maximum size of `i32::MAX` (not `u32`).
-    pub fn set_timeline_position(&self, timeline_position: u32) -> AnyResult {
-        self.0.set_timeline_position(timeline_position as i32)?;
+    /// Play it with [`CoreSystem::play_sound`], and free it with [`Sound::release`] once it's
+    /// no longer needed.
+    pub fn create_sound_from_memory(&self, data: &[u8], mode: SoundMode) -> AnyResult<Sound> {
+        sound::create_sound_from_memory(&self.0, data, mode)
+    }
 
-        Ok(())
+    /// Plays a sound on a free channel, mixed straight into the master channel group rather
+    /// than through any bus.
+    pub fn play_sound(&self, sound: &Sound) -> AnyResult<Channel> {
+        Ok(Channel(self.0.play_sound(sound.0.clone(), None, false)?))
     }
+}
 
-    /// Gets the timeline cursor position.
-    pub fn timeline_position(&self) -> AnyResult<u32> {
-        Ok(self.0.get_timeline_position()? as u32)
+/// A channel group mixes together everything routed into it, and runs it through its DSPs.
+/// Get the one everything ends up in with [`AudioEngine::master_channel_group`].
+#[derive(Debug)]
+pub struct ChannelGroup(fmod::ChannelGroup);
+
+impl ChannelGroup {
+    /// Gives access to the inner [`fmod::ChannelGroup`].
+    pub fn inner(&self) -> &fmod::ChannelGroup {
+        &self.0
     }
 
-    /// Sets the volume level.
-    /// This volume is applied as a scaling factor for the event volume.
-    /// It does not override the volume level set in FMOD Studio, nor any internal volume automation or modulation.
-    pub fn set_volume(&self, volume: f32) -> AnyResult {
-        self.0.set_volume(volume)?;
+    /// Adds `dsp` to the channel group at `index`. `0` is the head, which is the last DSP the
+    /// audio goes through, so a limiter goes there. Higher indices are earlier in the chain.
+    pub fn add_dsp(&self, index: i32, dsp: &Dsp) -> AnyResult {
+        self.0.add_dsp(index, dsp.0.clone())?;
 
         Ok(())
     }
 
-    /// Retrieves the volume level. See [`EventInstance::final_volume`] to get the final
-    /// volume after any modulation or changes.
-    pub fn volume(&self) -> AnyResult<f32> {
-        Ok(self.0.get_volume()?.0)
+    /// Removes `dsp` from the channel group. The DSP can be added again afterwards, or released
+    /// with [`Dsp::release`].
+    pub fn remove_dsp(&self, dsp: &Dsp) -> AnyResult {
+        self.0.remove_dsp(dsp.0.clone())?;
+
+        Ok(())
     }
+}
 
-    /// Retrieves the final volume multiplier. The final combined value returned combines the volume set
-    /// using [`EventInstance::set_volume`] with the result of any automation or modulation.
-    /// The final combined volume is calculated asynchronously once a frame.
-    ///
-    /// See [`EventInstance::volume`] to get the volume on this event alone.
-    pub fn final_volume(&self) -> AnyResult<f32> {
-        Ok(self.0.get_volume()?.1)
+/// A DSP processes audio, such as a limiter or an echo. Create one with
+/// [`CoreSystem::create_dsp_by_type`] and add it with [`ChannelGroup::add_dsp`].
+#[derive(Debug, Clone)]
+pub struct Dsp(fmod::Dsp);
+
+impl Dsp {
+    /// Gives access to the inner [`fmod::Dsp`].
+    pub fn inner(&self) -> &fmod::Dsp {
+        &self.0
     }
 
-    /// Retrieves the virtualization state.
-    ///
-    /// This function checks whether an event instance has been virtualized due to the polyphony limit
-    /// being exceeded.
-    pub fn is_virtual(&self) -> AnyResult<bool> {
-        Ok(self.0.is_virtual()?)
+    /// Sets a parameter of the DSP. Parameters are numbered per DSP type, such as
+    /// `FMOD_DSP_LIMITER_CEILING` being `1` on the limiter; see FMOD's documentation
+    /// of each effect.
+    pub fn set_parameter_float(&self, index: i32, value: f32) -> AnyResult {
+        self.0.set_parameter_float(index, value)?;
+
+        Ok(())
     }
 
-    /// Sets the position and velocity on this event instance.
-    pub fn set_position_velocity(&self, position: Vec2, velocity: Vec2) -> AnyResult {
-        self.0.set_3d_attributes(fmod::Attributes3d {
-            position: fmod::Vector::new(position.x, position.y, 0.0),
-            velocity: fmod::Vector::new(velocity.x, velocity.y, 0.0),
-            forward: fmod::Vector::new(0.0, 1.0, 0.0),
-            up: fmod::Vector::new(0.0, 0.0, 1.0),
-        })?;
+    /// Frees the DSP. It must be removed from every channel group it was added to first, see
+    /// [`ChannelGroup::remove_dsp`].
+    pub fn release(&self) -> AnyResult {
+        self.0.release()?;
 
         Ok(())
     }
+}
 
-    /// Gets the position and velocity on this event instance.
-    pub fn get_position_velocity(&self) -> AnyResult<AudioPositionVelocity> {
-        let atty = self.0.get_3d_attributes()?;
+/// The built-in DSPs which can be created with [`CoreSystem::create_dsp_by_type`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum DspType {
+    /// Keeps the level under a ceiling, which is what a master bus usually wants.
+    Limiter,
+    /// Reduces the dynamic range of the audio above a threshold.
+    Compressor,
+    /// Cuts frequencies above a cutoff.
+    Lowpass,
+    /// Cuts frequencies below a cutoff.
+    Highpass,
+    /// A three band equalizer.
+    ThreeEq,
+    /// An equalizer with up to five configurable bands.
+    MultibandEq,
+    /// Repeats the audio after a delay.
+    Echo,
+    /// A reverb like the one in FMOD Studio.
+    SfxReverb,
+    /// Changes pitch without changing speed.
+    PitchShift,
+}
 
-        Ok(AudioPositionVelocity {
-            position: Vec2::new(atty.position.x, atty.position.y),
-            velocity: Vec2::new(atty.velocity.x, atty.velocity.y),
-        })
+impl From<DspType> for fmod::DspType {
+    fn from(value: DspType) -> Self {
+        match value {
+            DspType::Limiter => fmod::DspType::Limiter,
+            DspType::Compressor => fmod::DspType::Compressor,
+            DspType::Lowpass => fmod::DspType::Lowpass,
+            DspType::Highpass => fmod::DspType::Highpass,
+            DspType::ThreeEq => fmod::DspType::ThreeEq,
+            DspType::MultibandEq => fmod::DspType::MultibandEq,
+            DspType::Echo => fmod::DspType::Echo,
+            DspType::SfxReverb => fmod::DspType::SfxReverb,
+            DspType::PitchShift => fmod::DspType::PitchShift,
+        }
     }
+}
 
-    /// Sets a given parameter by case-insensitive name.
-    ///
-    /// `ignore_seek_speed` specifies whether to ignore the parameter's seek speed and set the value immediately
-    /// The `value` will be set instantly regardless of `ignore_seek_speed` when the Event playback is
-    /// [`PlaybackState::Stopped`].
-    ///
-    /// If the specified parameter is an automatic parameter then an error is returned. If the event has no parameter
-    /// matching name then an error is returned.
-    pub fn set_parameter_by_name(
-        &self,
-        parameter: &str,
-        value: f32,
-        ignore_seek_speed: bool,
-    ) -> AnyResult {
-        self.0
-            .set_parameter_by_name(parameter, value, ignore_seek_speed)?;
+/// Information about an output driver, as returned by [`CoreSystem::driver_info`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DriverInfo {
+    /// The name of the device, as reported by the operating system.
+    pub name: String,
+    /// The sample rate the device operates at.
+    pub system_rate: i32,
+    /// The number of channels the device's speaker mode has.
+    pub speaker_mode_channels: i32,
+}
 
-        Ok(())
+/// Describes a parameter of an event, as returned by [`EventDescription::parameters`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParameterDescription {
+    /// The name of the parameter, such as `Area`.
+    pub name: String,
+    /// The id of the parameter, which is faster to look up than the name.
+    pub id: ParameterId,
+    /// The minimum value of the parameter.
+    pub minimum: f32,
+    /// The maximum value of the parameter.
+    pub maximum: f32,
+    /// The value the parameter starts at.
+    pub default: f32,
+}
+
+impl From<fmod::ParameterDescription> for ParameterDescription {
+    fn from(value: fmod::ParameterDescription) -> Self {
+        Self {
+            name: value.name,
+            id: value.id.into(),
+            minimum: value.minimum,
+            maximum: value.maximum,
+            default: value.defaultvalue,
+        }
     }
+}
 
-    /// Retrieves a parameter value by case-insensitive name. See [`EventInstance::get_final_parameter_by_name`] as well.
-    ///
-    /// Automatic parameters always return value as 0 since they can never have their value set from the public API.
-    pub fn get_parameter_by_name(&self, parameter: &str) -> AnyResult<f32> {
-        Ok(self.0.get_parameter_by_name(parameter)?.0)
+/// The value of a user property of an event, as returned by [`EventDescription::user_property`].
+#[derive(Debug, PartialEq, Clone)]
+pub enum UserProperty {
+    /// A whole number.
+    Integer(i32),
+    /// A flag.
+    Boolean(bool),
+    /// A decimal number.
+    Float(f32),
+    /// A piece of text.
+    String(String),
+}
+
+impl UserProperty {
+    /// Splits FMOD's user property into its name and value.
+    #[cfg(target_arch = "wasm32")]
+    fn from_fmod(property: fmod::UserProperty) -> AnyResult<(String, Self)> {
+        let value = match property.value {
+            fmod::UserPropertyValue::Integer(value) => UserProperty::Integer(value),
+            fmod::UserPropertyValue::Boolean(value) => UserProperty::Boolean(value),
+            fmod::UserPropertyValue::Float(value) => UserProperty::Float(value),
+            fmod::UserPropertyValue::String(value) => UserProperty::String(value),
+        };
+
+        Ok((property.name, value))
     }
 
-    /// Retrieves a parameter's final value by case-insensitive name. This is the final value of the parameter after
-    /// applying adjustments due to automation, modulation, seek speed, and parameter velocity to value. This is
-    /// calculated asynchronously once a frame.
-    ///
-    /// Automatic parameters always return value as 0 since they can never have their value set from the public API.
+    /// Splits FMOD's user property into its name and value.
     ///
-    /// See [`EventInstance::get_parameter_by_name`] for the value without other adjustments.
-    pub fn get_final_parameter_by_name(&self, parameter: &str) -> AnyResult<f32> {
-        Ok(self.0.get_parameter_by_name(parameter)?.1)
+    /// libfmod leaves the value as the raw C union, so it's read here by the type of the property.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn from_fmod(property: fmod::UserProperty) -> AnyResult<(String, Self)> {
+        use std::ffi::CStr;
+
+        // SAFETY: FMOD only sets the field of the union which matches the type, and the string
+        // lives as long as the event description does.
+        let value = unsafe {
+            match property.type_ {
+                fmod::UserPropertyType::Integer => UserProperty::Integer(property.union.intvalue),
+                fmod::UserPropertyType::Boolean => {
+                    UserProperty::Boolean(property.union.boolvalue != 0)
+                }
+                fmod::UserPropertyType::Float => UserProperty::Float(property.union.floatvalue),
+                fmod::UserPropertyType::String => UserProperty::String(
+                    CStr::from_ptr(property.union.stringvalue)
+                        .to_string_lossy()
+                        .into_owned(),
+                ),
+            }
+        };
+
+        Ok((property.name, value))
     }
+}
 
-    /// Stops playback with a fadeout, allowing AHDSR modulators to complete their release, and DSP effect tails to play out.
-    /// This is the preferred way to stop audio.
-    ///
-    /// If you need to stop immediately, use [`EventInstance::stop_immediately`].
-    pub fn stop(&self) -> AnyResult {
-        self.0.stop(fmod::StopMode::AllowFadeout)?;
+/// The id of a parameter, found in its [`ParameterDescription`]. Ids stay the same for as long
+/// as the bank holding the parameter is loaded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ParameterId {
+    /// The first half of the id.
+    pub data_1: u32,
+    /// The second half of the id.
+    pub data_2: u32,
+}
 
-        Ok(())
+impl From<fmod::ParameterId> for ParameterId {
+    fn from(value: fmod::ParameterId) -> Self {
+        Self {
+            data_1: value.data_1,
+            data_2: value.data_2,
+        }
     }
+}
 
-    /// Stops playback immediately. If you need to stop with a fadeout, use [`EventInstance::stop`].
-    pub fn stop_immediately(&self) -> AnyResult {
-        self.0.stop(fmod::StopMode::Immediate)?;
+impl From<ParameterId> for fmod::ParameterId {
+    fn from(value: ParameterId) -> Self {
+        fmod::ParameterId {
+            data_1: value.data_1,
+            data_2: value.data_2,
+        }
+    }
+}
 
-        Ok(())
+/// A 128-bit GUID which FMOD Studio gives to banks, events and other objects. Unlike their
+/// paths, GUIDs don't need the strings bank to be loaded.
+///
+/// This formats and parses as `{01234567-89ab-cdef-0123-456789abcdef}`, the format FMOD Studio
+/// uses when exporting GUIDs. When parsing, the braces are optional, but the hex digits must be
+/// grouped exactly like that.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Guid(pub u128);
+
+impl Display for Guid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let value = self.0;
+        write!(
+            f,
+            "{{{:08x}-{:04x}-{:04x}-{:04x}-{:012x}}}",
+            value >> 96,
+            (value >> 80) & 0xffff,
+            (value >> 64) & 0xffff,
+            (value >> 48) & 0xffff,
+            value & 0xffff_ffff_ffff,
+        )
     }
+}
 
-    /// Pauses the given event. If the event is already paused, this doesn't do anything.
-    pub fn pause(&self) -> AnyResult {
-        self.0.set_paused(true)?;
-        Ok(())
+impl FromStr for Guid {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unbraced = s
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+            .unwrap_or(s);
+
+        let mut value = 0;
+        let mut groups = unbraced.split('-');
+        for len in [8, 4, 4, 4, 12] {
+            let group = groups.next().unwrap_or_default();
+            // checking the digits ourselves, as `from_str_radix` also takes a leading `+`
+            if group.len() != len || !group.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(eyre!("`{}` is not a GUID", s));
+            }
+
+            value = (value << (len * 4)) | u128::from_str_radix(group, 16)?;
+        }
+        if groups.next().is_some() {
+            return Err(eyre!("`{}` is not a GUID", s));
+        }
+
+        Ok(Guid(value))
     }
+}
 
-    /// Unpauses the given event. If the event isn't paused, this doesn't do anything.
-    pub fn unpause(&self) -> AnyResult {
-        self.0.set_paused(false)?;
-        Ok(())
+impl From<fmod::Guid> for Guid {
+    fn from(value: fmod::Guid) -> Self {
+        let mut bytes = [0; 16];
+        bytes[0..4].copy_from_slice(&value.data_1.to_be_bytes());
+        bytes[4..6].copy_from_slice(&value.data_2.to_be_bytes());
+        bytes[6..8].copy_from_slice(&value.data_3.to_be_bytes());
+        bytes[8..16].copy_from_slice(&value.data_4);
+
+        Guid(u128::from_be_bytes(bytes))
     }
+}
 
-    /// Returns the pause state of the event. Note that this is different from [`EventInstance::playback_state`].
-    pub fn is_paused(&self) -> AnyResult<bool> {
-        Ok(self.0.get_paused()?)
+impl From<Guid> for fmod::Guid {
+    fn from(value: Guid) -> Self {
+        let bytes = value.0.to_be_bytes();
+        let mut data_4 = [0; 8];
+        data_4.copy_from_slice(&bytes[8..16]);
+
+        fmod::Guid {
+            data_1: (value.0 >> 96) as u32,
+            data_2: (value.0 >> 80) as u16,
+            data_3: (value.0 >> 64) as u16,
+            data_4,
+        }
     }
+}
 
-    /// You can poll this function to track the playback state of an event instance.
-    ///
-    /// If the instance is invalid, then the state will be set to [`PlaybackState::Stopped`].
-    ///
-    /// Note that the playback state can be [`PlaybackState::Playing`] while [`EventInstance::is_paused`] also
-    /// return true! In a sense, this is mostly "lifetime" state, rather than just a playback state.
-    pub fn playback_state(&self) -> AnyResult<PlaybackState> {
-        let state = self.0.get_playback_state()?;
+/// Loading state of banks and their sample data.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LoadingState {
+    /// Currently unloading.
+    Unloading,
+    /// Not loaded.
+    Unloaded,
+    /// Loading in progress.
+    Loading,
+    /// Loaded and ready to play.
+    Loaded,
+    /// Failed to load.
+    Error,
+}
 
-        Ok(state.into())
+impl From<fmod::LoadingState> for LoadingState {
+    fn from(value: fmod::LoadingState) -> Self {
+        match value {
+            fmod::LoadingState::Unloading => LoadingState::Unloading,
+            fmod::LoadingState::Unloaded => LoadingState::Unloaded,
+            fmod::LoadingState::Loading => LoadingState::Loading,
+            fmod::LoadingState::Loaded => LoadingState::Loaded,
+            fmod::LoadingState::Error => LoadingState::Error,
+        }
     }
 }
 
 /// Playback state of various objects.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlaybackState {
     /// Currently playing, though may be paused.
     Playing,
@@ -551,8 +3861,23 @@ impl From<fmod::PlaybackState> for PlaybackState {
     }
 }
 
+impl std::fmt::Display for PlaybackState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            PlaybackState::Playing => "Playing",
+            PlaybackState::Sustaining => "Sustaining",
+            PlaybackState::Stopped => "Stopped",
+            PlaybackState::Starting => "Starting",
+            PlaybackState::Stopping => "Stopping",
+        };
+
+        f.write_str(name)
+    }
+}
+
 /// The name of an event property in FMOD.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventProperty {
     /// Priority to set on Core channels created by this event instance, or -1 for default.
     /// Range: [-1, 256], default: -1
@@ -587,9 +3912,212 @@ impl From<EventProperty> for fmod::EventProperty {
     }
 }
 
+impl std::fmt::Display for EventProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            EventProperty::ChannelPriority => "Channel Priority",
+            EventProperty::ScheduleDelay => "Schedule Delay",
+            EventProperty::ScheduleLookahead => "Schedule Lookahead",
+            EventProperty::MinimumDistance => "Minimum Distance",
+            EventProperty::MaximumDistance => "Maximum Distance",
+            EventProperty::Cooldown => "Cooldown",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Every [`EventProperty`] at once, for [`EventInstance::set_properties`] and
+/// [`EventInstance::properties`]. `None` leaves a property as it is when setting, and means
+/// the event's default (FMOD's `-1`) when getting.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventProperties {
+    /// See [`EventProperty::ChannelPriority`].
+    pub channel_priority: Option<f32>,
+    /// See [`EventProperty::ScheduleDelay`].
+    pub schedule_delay: Option<f32>,
+    /// See [`EventProperty::ScheduleLookahead`].
+    pub schedule_lookahead: Option<f32>,
+    /// See [`EventProperty::MinimumDistance`].
+    pub minimum_distance: Option<f32>,
+    /// See [`EventProperty::MaximumDistance`].
+    pub maximum_distance: Option<f32>,
+    /// See [`EventProperty::Cooldown`].
+    pub cooldown: Option<f32>,
+}
+
+impl EventProperties {
+    /// Pairs each property with its field.
+    fn fields(&self) -> [(EventProperty, Option<f32>); 6] {
+        [
+            (EventProperty::ChannelPriority, self.channel_priority),
+            (EventProperty::ScheduleDelay, self.schedule_delay),
+            (EventProperty::ScheduleLookahead, self.schedule_lookahead),
+            (EventProperty::MinimumDistance, self.minimum_distance),
+            (EventProperty::MaximumDistance, self.maximum_distance),
+            (EventProperty::Cooldown, self.cooldown),
+        ]
+    }
+}
+
+/// How event instances stop, such as with [`EventInstance::stop`] or [`AudioEngine::stop_all`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopMode {
+    /// Allows AHDSR modulators to complete their release, and DSP effect tails to play out.
+    /// This is the preferred way to stop audio.
+    #[default]
+    AllowFadeout,
+    /// Stops immediately.
+    Immediate,
+}
+
+impl From<StopMode> for fmod::StopMode {
+    fn from(value: StopMode) -> Self {
+        match value {
+            StopMode::AllowFadeout => fmod::StopMode::AllowFadeout,
+            StopMode::Immediate => fmod::StopMode::Immediate,
+        }
+    }
+}
+
+impl From<fmod::StopMode> for StopMode {
+    fn from(value: fmod::StopMode) -> Self {
+        match value {
+            fmod::StopMode::AllowFadeout => StopMode::AllowFadeout,
+            fmod::StopMode::Immediate => StopMode::Immediate,
+        }
+    }
+}
+
 /// The position and velocity set on various FMOD objects.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AudioPositionVelocity {
     pub position: Vec2,
     pub velocity: Vec2,
 }
+
+/// The position and velocity set on various FMOD objects, including elevation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AudioPositionVelocity3d {
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+/// The attributes of a listener as FMOD sees them, returned by
+/// [`AudioEngine::listener_attributes`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListenerAttributes {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    /// Where the listener is facing.
+    pub forward: Vec3,
+    /// Which way is up for the listener.
+    pub up: Vec3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUID: Guid = Guid(0x01234567_89ab_cdef_0123_456789abcdef);
+
+    #[test]
+    fn guid_display() {
+        assert_eq!(GUID.to_string(), "{01234567-89ab-cdef-0123-456789abcdef}");
+    }
+
+    #[test]
+    fn guid_from_str() {
+        assert_eq!(
+            "{01234567-89ab-cdef-0123-456789abcdef}"
+                .parse::<Guid>()
+                .unwrap(),
+            GUID
+        );
+        assert_eq!(
+            "01234567-89AB-CDEF-0123-456789ABCDEF"
+                .parse::<Guid>()
+                .unwrap(),
+            GUID
+        );
+        assert_eq!(GUID.to_string().parse::<Guid>().unwrap(), GUID);
+    }
+
+    #[test]
+    fn guid_from_str_rejects_other_layouts() {
+        for s in [
+            "",
+            "{{01234567-89ab-cdef-0123-456789abcdef}}",
+            "{01234567-89ab-cdef-0123-456789abcdef",
+            "01234567-89ab-cdef-0123-456789abcdef}",
+            "0123456789abcdef0123456789abcdef",
+            "01234567--89ab-cdef-0123-456789abcdef",
+            "01234567-89ab-cdef-0123-456789abcdef-",
+            "0123-4567-89ab-cdef-0123-456789abcdef",
+            "+1234567-89ab-cdef-0123-456789abcdef",
+            "01234567-89ab-cdef-0123-456789abcdeg",
+            " 01234567-89ab-cdef-0123-456789abcdef",
+        ] {
+            assert!(s.parse::<Guid>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn guid_fmod_round_trip() {
+        let fmod_guid = fmod::Guid::from(GUID);
+        assert_eq!(fmod_guid.data_1, 0x01234567);
+        assert_eq!(fmod_guid.data_2, 0x89ab);
+        assert_eq!(fmod_guid.data_3, 0xcdef);
+        assert_eq!(
+            fmod_guid.data_4,
+            [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]
+        );
+        assert_eq!(Guid::from(fmod_guid), GUID);
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn update_by_advances_before_banks_load() {
+        let engine = AudioEngine::new_headless().unwrap();
+        let capture = engine.capture_output().unwrap();
+
+        engine.update_by(1).unwrap();
+
+        assert!(capture.channels().unwrap() > 0);
+        assert!(capture.read(&mut [0.0; 16]).unwrap() > 0);
+    }
+
+    #[test]
+    fn db_to_gain_silence() {
+        assert_eq!(db_to_gain(f32::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn db_to_gain_unity() {
+        assert_eq!(db_to_gain(0.0), 1.0);
+    }
+
+    #[test]
+    fn gain_to_db_silence() {
+        assert_eq!(gain_to_db(0.0), f32::NEG_INFINITY);
+        assert_eq!(gain_to_db(-0.5), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn gain_to_db_unity() {
+        assert_eq!(gain_to_db(1.0), 0.0);
+    }
+
+    #[test]
+    fn db_round_trip() {
+        for db in [-60.0, -12.0, -6.0, 0.0, 6.0] {
+            assert!((gain_to_db(db_to_gain(db)) - db).abs() < 1e-4, "{} dB", db);
+        }
+        assert_eq!(gain_to_db(db_to_gain(f32::NEG_INFINITY)), f32::NEG_INFINITY);
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     rc::Rc,
 };
 
-use fmod_test_bed::{AudioEngine, EventInstance};
+use fmod_test_bed::{AudioEngine, EventInstance, StopMode};
 use u64_id::U64Id;
 
 #[cfg(target_arch = "wasm32")]
@@ -71,7 +71,10 @@ pub fn main() {
     let engine = Rc::new(RefCell::new(setup()));
 
     while tick(engine.borrow_mut()) {
-        std::thread::sleep(std::time::Duration::from_secs_f64(1. / 144.));
+        // a non-realtime engine only advances when updated, so there's nothing to wait for
+        if engine.borrow().engine.is_realtime() {
+            std::thread::sleep(std::time::Duration::from_secs_f64(1. / 144.));
+        }
     }
 }
 
@@ -117,12 +120,16 @@ fn tick(mut game: RefMut<Game>) -> bool {
         game.current = game.engine.play_event("event:/Music/Level 02").ok();
     }
     if game.tick_count == next_check() {
-        agnostic_print!("- AudioEngine::set_global_mute(true)");
-        game.engine.set_global_mute(true);
+        agnostic_print!(
+            "- AudioEngine::set_global_mute(true) -> {:?}",
+            game.engine.set_global_mute(true),
+        );
     }
     if game.tick_count == next_check() {
-        agnostic_print!("- AudioEngine::set_global_mute(false)");
-        game.engine.set_global_mute(false);
+        agnostic_print!(
+            "- AudioEngine::set_global_mute(false) -> {:?}",
+            game.engine.set_global_mute(false),
+        );
     }
     if game.tick_count == next_check() {
         agnostic_print!(
@@ -137,9 +144,12 @@ fn tick(mut game: RefMut<Game>) -> bool {
         );
     }
     if game.tick_count == next_check() {
-        agnostic_print!("- AudioEngine::set_global_parameter(\"Area\", 70.0) !! This one doesn't work with example because
-            I don't know how to use FMOD :(");
-        game.engine.set_global_parameter("Area", 70.0).ok();
+        agnostic_print!(
+            "- AudioEngine::set_global_parameter(\"Area\", 70.0) -> {:?}",
+            game.engine
+                .set_global_parameter("Area", 70.0)
+                .map_err(|err| err.to_string()),
+        );
     }
     if game.tick_count == next_check() {
         agnostic_print!(
@@ -233,8 +243,12 @@ fn tick(mut game: RefMut<Game>) -> bool {
         );
     }
     if game.tick_count == next_check() {
-        agnostic_print!("- EventInstance::stop()");
-        game.current.as_ref().unwrap().stop().unwrap();
+        agnostic_print!("- EventInstance::stop(StopMode::AllowFadeout)");
+        game.current
+            .as_ref()
+            .unwrap()
+            .stop(StopMode::AllowFadeout)
+            .unwrap();
     }
     if game.tick_count == next_check() {
         agnostic_print!(
@@ -310,8 +324,10 @@ fn tick(mut game: RefMut<Game>) -> bool {
         game.current.as_ref().unwrap().stop_immediately().unwrap();
     }
     if game.tick_count == next_check() {
-        agnostic_print!("- EventInstance::unload_banks()");
-        game.engine.unload_banks();
+        agnostic_print!(
+            "- EventInstance::unload_banks() -> {:?}",
+            game.engine.unload_banks(),
+        );
 
         return false;
     }
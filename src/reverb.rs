@@ -0,0 +1,289 @@
+//! Reverb settings for the core system's global reverb, set with
+//! [`AudioEngine::set_reverb_properties`](crate::AudioEngine::set_reverb_properties).
+
+use crate::fmod;
+
+/// Settings of a reverb, in the same units as FMOD's `FMOD_REVERB_PROPERTIES`.
+///
+/// The named constructors are FMOD's own presets, such as [`ReverbProperties::cave`], which are
+/// a good place to start before tweaking individual fields.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReverbProperties {
+    /// Reverberation decay time in milliseconds. Range: [100, 20000], default: 1500
+    pub decay_time: f32,
+    /// Initial reflection delay time in milliseconds. Range: [0, 300], default: 7
+    pub early_delay: f32,
+    /// Late reverberation delay time relative to the initial reflection, in milliseconds.
+    /// Range: [0, 100], default: 11
+    pub late_delay: f32,
+    /// Reference high frequency in Hz. Range: [20, 20000], default: 5000
+    pub hf_reference: f32,
+    /// High-frequency to mid-frequency decay time ratio, in percent. Range: [10, 100], default: 50
+    pub hf_decay_ratio: f32,
+    /// Value that controls the echo density in the late reverberation decay, in percent.
+    /// Range: [10, 100], default: 50
+    pub diffusion: f32,
+    /// Value that controls the modal density in the late reverberation decay, in percent.
+    /// Range: [0, 100], default: 100
+    pub density: f32,
+    /// Reference low frequency in Hz. Range: [20, 1000], default: 250
+    pub low_shelf_frequency: f32,
+    /// Relative room effect level at low frequencies, in dB. Range: [-36, 12], default: 0
+    pub low_shelf_gain: f32,
+    /// Relative room effect level at high frequencies, in Hz. Range: [20, 20000], default: 20000
+    pub high_cut: f32,
+    /// Early reflections level relative to room effect, in percent. Range: [0, 100], default: 50
+    pub early_late_mix: f32,
+    /// Room effect level at mid frequencies, in dB. Range: [-80, 20], default: -6
+    pub wet_level: f32,
+}
+
+impl ReverbProperties {
+    /// Fills in the fields in the same order as FMOD's `FMOD_PRESET_*` macros.
+    fn from_preset(values: [f32; 12]) -> Self {
+        let [decay_time, early_delay, late_delay, hf_reference, hf_decay_ratio, diffusion, density, low_shelf_frequency, low_shelf_gain, high_cut, early_late_mix, wet_level] =
+            values;
+
+        Self {
+            decay_time,
+            early_delay,
+            late_delay,
+            hf_reference,
+            hf_decay_ratio,
+            diffusion,
+            density,
+            low_shelf_frequency,
+            low_shelf_gain,
+            high_cut,
+            early_late_mix,
+            wet_level,
+        }
+    }
+
+    /// Off, which disables the reverb.
+    pub fn off() -> Self {
+        Self::from_preset([
+            1000.0, 7.0, 11.0, 5000.0, 100.0, 100.0, 100.0, 250.0, 0.0, 20.0, 96.0, -80.0,
+        ])
+    }
+
+    /// Generic.
+    pub fn generic() -> Self {
+        Self::from_preset([
+            1500.0, 7.0, 11.0, 5000.0, 83.0, 100.0, 100.0, 250.0, 0.0, 14500.0, 96.0, -8.0,
+        ])
+    }
+
+    /// Padded cell.
+    pub fn padded_cell() -> Self {
+        Self::from_preset([
+            170.0, 1.0, 2.0, 5000.0, 10.0, 100.0, 100.0, 250.0, 0.0, 160.0, 84.0, -7.8,
+        ])
+    }
+
+    /// Room.
+    pub fn room() -> Self {
+        Self::from_preset([
+            400.0, 2.0, 3.0, 5000.0, 83.0, 100.0, 100.0, 250.0, 0.0, 6050.0, 88.0, -9.4,
+        ])
+    }
+
+    /// Bathroom.
+    pub fn bathroom() -> Self {
+        Self::from_preset([
+            1500.0, 7.0, 11.0, 5000.0, 54.0, 100.0, 60.0, 250.0, 0.0, 2900.0, 83.0, 0.5,
+        ])
+    }
+
+    /// Living room.
+    pub fn living_room() -> Self {
+        Self::from_preset([
+            500.0, 3.0, 4.0, 5000.0, 10.0, 100.0, 100.0, 250.0, 0.0, 160.0, 58.0, -19.0,
+        ])
+    }
+
+    /// Stone room.
+    pub fn stone_room() -> Self {
+        Self::from_preset([
+            2300.0, 12.0, 17.0, 5000.0, 64.0, 100.0, 100.0, 250.0, 0.0, 7800.0, 71.0, -8.5,
+        ])
+    }
+
+    /// Auditorium.
+    pub fn auditorium() -> Self {
+        Self::from_preset([
+            4300.0, 20.0, 30.0, 5000.0, 59.0, 100.0, 100.0, 250.0, 0.0, 5850.0, 64.0, -11.7,
+        ])
+    }
+
+    /// Concert hall.
+    pub fn concert_hall() -> Self {
+        Self::from_preset([
+            3900.0, 20.0, 29.0, 5000.0, 70.0, 100.0, 100.0, 250.0, 0.0, 5650.0, 80.0, -9.8,
+        ])
+    }
+
+    /// Cave.
+    pub fn cave() -> Self {
+        Self::from_preset([
+            2900.0, 15.0, 22.0, 5000.0, 100.0, 100.0, 100.0, 250.0, 0.0, 20000.0, 59.0, -11.3,
+        ])
+    }
+
+    /// Arena.
+    pub fn arena() -> Self {
+        Self::from_preset([
+            7200.0, 20.0, 30.0, 5000.0, 33.0, 100.0, 100.0, 250.0, 0.0, 4500.0, 80.0, -9.6,
+        ])
+    }
+
+    /// Hangar.
+    pub fn hangar() -> Self {
+        Self::from_preset([
+            10000.0, 20.0, 30.0, 5000.0, 23.0, 100.0, 100.0, 250.0, 0.0, 3400.0, 72.0, -7.4,
+        ])
+    }
+
+    /// Carpetted hallway.
+    pub fn carpetted_hallway() -> Self {
+        Self::from_preset([
+            300.0, 2.0, 30.0, 5000.0, 10.0, 100.0, 100.0, 250.0, 0.0, 500.0, 56.0, -24.0,
+        ])
+    }
+
+    /// Hallway.
+    pub fn hallway() -> Self {
+        Self::from_preset([
+            1500.0, 7.0, 11.0, 5000.0, 59.0, 100.0, 100.0, 250.0, 0.0, 7800.0, 87.0, -5.5,
+        ])
+    }
+
+    /// Stone corridor.
+    pub fn stone_corridor() -> Self {
+        Self::from_preset([
+            270.0, 13.0, 20.0, 5000.0, 79.0, 100.0, 100.0, 250.0, 0.0, 9000.0, 86.0, -6.0,
+        ])
+    }
+
+    /// Alley.
+    pub fn alley() -> Self {
+        Self::from_preset([
+            1500.0, 7.0, 11.0, 5000.0, 86.0, 100.0, 100.0, 250.0, 0.0, 8300.0, 80.0, -9.8,
+        ])
+    }
+
+    /// Forest.
+    pub fn forest() -> Self {
+        Self::from_preset([
+            1500.0, 162.0, 88.0, 5000.0, 54.0, 79.0, 100.0, 250.0, 0.0, 760.0, 94.0, -12.3,
+        ])
+    }
+
+    /// City.
+    pub fn city() -> Self {
+        Self::from_preset([
+            1500.0, 7.0, 11.0, 5000.0, 67.0, 50.0, 100.0, 250.0, 0.0, 4050.0, 66.0, -26.0,
+        ])
+    }
+
+    /// Mountains.
+    pub fn mountains() -> Self {
+        Self::from_preset([
+            1500.0, 300.0, 100.0, 5000.0, 21.0, 27.0, 100.0, 250.0, 0.0, 1220.0, 82.0, -24.0,
+        ])
+    }
+
+    /// Quarry.
+    pub fn quarry() -> Self {
+        Self::from_preset([
+            1500.0, 61.0, 25.0, 5000.0, 83.0, 100.0, 100.0, 250.0, 0.0, 3400.0, 100.0, -5.0,
+        ])
+    }
+
+    /// Plain.
+    pub fn plain() -> Self {
+        Self::from_preset([
+            1500.0, 179.0, 100.0, 5000.0, 50.0, 21.0, 100.0, 250.0, 0.0, 1670.0, 65.0, -28.0,
+        ])
+    }
+
+    /// Parking lot.
+    pub fn parking_lot() -> Self {
+        Self::from_preset([
+            1700.0, 8.0, 12.0, 5000.0, 100.0, 100.0, 100.0, 250.0, 0.0, 20000.0, 56.0, -19.5,
+        ])
+    }
+
+    /// Sewer pipe.
+    pub fn sewer_pipe() -> Self {
+        Self::from_preset([
+            2800.0, 14.0, 21.0, 5000.0, 14.0, 80.0, 60.0, 250.0, 0.0, 3400.0, 66.0, 1.2,
+        ])
+    }
+
+    /// Underwater.
+    pub fn underwater() -> Self {
+        Self::from_preset([
+            1500.0, 7.0, 11.0, 5000.0, 10.0, 100.0, 100.0, 250.0, 0.0, 500.0, 92.0, 7.0,
+        ])
+    }
+}
+
+/// A kind of place the player can be in, set with
+/// [`AudioEngine::set_environment`](crate::AudioEngine::set_environment) when they enter a zone.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Environment {
+    /// A cave, with long echoes off stone.
+    Cave,
+    /// A small room.
+    Room,
+    /// A large, open arena.
+    Arena,
+    /// Underwater, muffled and washy.
+    Underwater,
+}
+
+impl Environment {
+    /// The reverb preset used for the environment.
+    pub fn reverb_properties(self) -> ReverbProperties {
+        match self {
+            Environment::Cave => ReverbProperties::cave(),
+            Environment::Room => ReverbProperties::room(),
+            Environment::Arena => ReverbProperties::arena(),
+            Environment::Underwater => ReverbProperties::underwater(),
+        }
+    }
+
+    /// The value of the `Environment` global parameter for the environment, which is its
+    /// position in a labeled parameter with the labels `Cave`, `Room`, `Arena` and `Underwater`,
+    /// in that order.
+    pub fn parameter_value(self) -> f32 {
+        match self {
+            Environment::Cave => 0.0,
+            Environment::Room => 1.0,
+            Environment::Arena => 2.0,
+            Environment::Underwater => 3.0,
+        }
+    }
+}
+
+impl From<ReverbProperties> for fmod::ReverbProperties {
+    fn from(value: ReverbProperties) -> Self {
+        fmod::ReverbProperties {
+            decay_time: value.decay_time,
+            early_delay: value.early_delay,
+            late_delay: value.late_delay,
+            hf_reference: value.hf_reference,
+            hf_decay_ratio: value.hf_decay_ratio,
+            diffusion: value.diffusion,
+            density: value.density,
+            low_shelf_frequency: value.low_shelf_frequency,
+            low_shelf_gain: value.low_shelf_gain,
+            high_cut: value.high_cut,
+            early_late_mix: value.early_late_mix,
+            wet_level: value.wet_level,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Sounds which don't live in a bank, such as procedural beeps or streamed voice files, played
+//! straight through the core system with [`CoreSystem::play_sound`](crate::CoreSystem::play_sound)
+//! rather than as events.
+
+use bitflags::bitflags;
+
+use crate::{fmod, AnyResult};
+
+/// `FMOD_OPENMEMORY`, which makes FMOD read the sound out of a buffer and copy it, so the
+/// buffer doesn't have to outlive the call.
+const MODE_OPEN_MEMORY: u32 = 0x00000800;
+
+bitflags! {
+    /// How a sound is created with
+    /// [`CoreSystem::create_sound_from_memory`](crate::CoreSystem::create_sound_from_memory).
+    /// These are FMOD's `FMOD_MODE` flags which make sense for sounds read from memory.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub struct SoundMode: u32 {
+        /// Standard behaviour, which is a 2D sound that plays once.
+        const DEFAULT = 0x00000000;
+        /// Plays once.
+        const LOOP_OFF = 0x00000001;
+        /// Loops forever, until the channel is stopped.
+        const LOOP_NORMAL = 0x00000002;
+        /// Loops back and forth, until the channel is stopped.
+        const LOOP_BIDI = 0x00000004;
+        /// Ignores 3D attributes, which is the default.
+        const MODE_2D = 0x00000008;
+        /// Makes the sound positional.
+        const MODE_3D = 0x00000010;
+        /// Decodes the sound while it plays rather than all up front, which suits long sounds
+        /// such as voice lines. A streamed sound can only be played on one channel at a time.
+        const CREATE_STREAM = 0x00000080;
+        /// Decodes the whole sound up front, which suits short sounds played often.
+        const CREATE_SAMPLE = 0x00000100;
+        /// Keeps the sound compressed in memory and decodes it as it plays.
+        const CREATE_COMPRESSED_SAMPLE = 0x00000200;
+        /// Measures the length of the sound accurately when it's created, which takes longer.
+        const ACCURATE_TIME = 0x00004000;
+    }
+}
+
+/// A sound read out of memory, as returned by
+/// [`CoreSystem::create_sound_from_memory`](crate::CoreSystem::create_sound_from_memory).
+#[derive(Debug, Clone)]
+pub struct Sound(pub(crate) fmod::Sound);
+
+impl Sound {
+    /// Gives access to the inner [`fmod::Sound`].
+    pub fn inner(&self) -> &fmod::Sound {
+        &self.0
+    }
+
+    /// Frees the sound, stopping every channel playing it.
+    pub fn release(&self) -> AnyResult {
+        self.0.release()?;
+
+        Ok(())
+    }
+}
+
+/// A sound playing through the core system, as returned by
+/// [`CoreSystem::play_sound`](crate::CoreSystem::play_sound).
+///
+/// FMOD reuses a channel once its sound ends or is stopped, after which this handle is no longer
+/// valid and its methods return errors.
+#[derive(Debug, Clone)]
+pub struct Channel(pub(crate) fmod::Channel);
+
+impl Channel {
+    /// Gives access to the inner [`fmod::Channel`].
+    pub fn inner(&self) -> &fmod::Channel {
+        &self.0
+    }
+
+    /// Sets the volume of the channel as linear gain, where `1.0` is the volume of the sound.
+    pub fn set_volume(&self, volume: f32) -> AnyResult {
+        self.0.set_volume(volume)?;
+
+        Ok(())
+    }
+
+    /// Stops the channel right away.
+    pub fn stop(&self) -> AnyResult {
+        self.0.stop()?;
+
+        Ok(())
+    }
+
+    /// Checks whether the channel is still playing. A channel which was stopped or reached the
+    /// end of its sound is no longer valid, which counts as not playing.
+    pub fn is_playing(&self) -> bool {
+        self.0.is_playing().unwrap_or(false)
+    }
+}
+
+/// Creates a sound out of an encoded file in `data`, which FMOD copies.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn create_sound_from_memory(
+    system: &fmod::System,
+    data: &[u8],
+    mode: SoundMode,
+) -> AnyResult<Sound> {
+    let sound = system.create_sound_from_memory(data, mode.bits() | MODE_OPEN_MEMORY)?;
+
+    Ok(Sound(sound))
+}
+
+/// Creates a sound out of an encoded file in `data`, which FMOD copies.
+///
+/// libfmod only takes names as strings here, which can't hold arbitrary bytes, so this goes
+/// through the raw FFI with the length in the extra info.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn create_sound_from_memory(
+    system: &fmod::System,
+    data: &[u8],
+    mode: SoundMode,
+) -> AnyResult<Sound> {
+    use color_eyre::eyre::eyre;
+    use libfmod::ffi;
+    use std::{ffi::c_char, ptr};
+
+    // SAFETY: FMOD expects unused fields of the extra info to be zeroed.
+    let mut exinfo: ffi::FMOD_CREATESOUNDEXINFO = unsafe { std::mem::zeroed() };
+    exinfo.cbsize = std::mem::size_of::<ffi::FMOD_CREATESOUNDEXINFO>() as i32;
+    exinfo.length = data.len() as u32;
+
+    let mut sound = ptr::null_mut();
+    // SAFETY: the data and extra info outlive the call, and FMOD copies the data in this mode.
+    let result = unsafe {
+        ffi::FMOD_System_CreateSound(
+            system.as_mut_ptr(),
+            data.as_ptr() as *const c_char,
+            mode.bits() | MODE_OPEN_MEMORY,
+            &mut exinfo,
+            &mut sound,
+        )
+    };
+    if result != ffi::FMOD_OK {
+        return Err(eyre!("FMOD_System_CreateSound failed with code {}", result));
+    }
+
+    Ok(Sound(fmod::Sound::from(sound)))
+}
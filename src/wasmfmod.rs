@@ -19,10 +19,29 @@ macro_rules! err_fmod {
         Error::Fmod {
             function: $function.to_string(),
             code: $code as i32,
-            message: "".to_string(),
+            message: FMOD_ErrorString($code as i32),
         }
     };
 }
+#[wasm_bindgen]
+extern "C" {
+    // Same as libfmod's use of FMOD_ErrorString from fmod_errors.h, which
+    // FMOD's HTML5 build exposes as FMOD.ErrorString.
+    #[wasm_bindgen]
+    fn FMOD_ErrorString(result: i32) -> String;
+    #[wasm_bindgen]
+    fn Memory_GetStats(blocking: bool) -> I32I32JSResult;
+}
+
+// FMOD_Memory_GetStats isn't a method of any object, so it's a free function.
+// Returns the current and the peak number of bytes allocated.
+pub fn memory_get_stats(blocking: bool) -> Result<(i32, i32), Error> {
+    let result = Memory_GetStats(blocking);
+    match FMODResult::from(result.0) {
+        FMODResult::Ok => Ok((result.1, result.2)),
+        err => Err(err_fmod!("Memory_GetStats", err)),
+    }
+}
 
 // Studio wrapper and binding
 #[derive(Debug, Clone)]
@@ -60,6 +79,13 @@ impl Studio {
             err => Err(err_fmod!("Studio_System_Initialize", err)),
         }
     }
+    pub fn release(&self) -> Result<(), Error> {
+        let result = Studio_System_Release(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_System_Release", err)),
+        }
+    }
 
     pub fn load_bank_memory(&self, buffer: &[u8], flags: LoadBank) -> Result<Bank, Error> {
         let result = Studio_System_LoadBankMemory(&self.opaque, buffer, flags.bits());
@@ -82,6 +108,13 @@ impl Studio {
             err => Err(err_fmod!("Studio_System_GetEvent", err)),
         }
     }
+    pub fn get_event_by_id(&self, id: Guid) -> Result<EventDescription, Error> {
+        let result = Studio_System_GetEventByID(&self.opaque, &id.to_js_string());
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(EventDescription { opaque: result.1 }),
+            err => Err(err_fmod!("Studio_System_GetEventByID", err)),
+        }
+    }
     pub fn get_bus(&self, path_or_id: &str) -> Result<Bus, Error> {
         let result = Studio_System_GetBus(&self.opaque, path_or_id);
         match FMODResult::from(result.0) {
@@ -101,6 +134,59 @@ impl Studio {
             err => Err(err_fmod!("Studio_System_SetParameterByName", err)),
         }
     }
+    pub fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignore_seek_speed: bool,
+    ) -> Result<(), Error> {
+        let result = Studio_System_SetParameterByID(&self.opaque, id, value, ignore_seek_speed);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_System_SetParameterByID", err)),
+        }
+    }
+    pub fn get_parameter_description_by_name(
+        &self,
+        name: &str,
+    ) -> Result<ParameterDescription, Error> {
+        let result = Studio_System_GetParameterDescriptionByName(&self.opaque, name);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!(
+                "Studio_System_GetParameterDescriptionByName",
+                err
+            )),
+        }
+    }
+    pub fn set_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
+        let result = Studio_System_SetNumListeners(&self.opaque, numlisteners);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_System_SetNumListeners", err)),
+        }
+    }
+    pub fn get_num_listeners(&self) -> Result<i32, Error> {
+        let result = Studio_System_GetNumListeners(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_System_GetNumListeners", err)),
+        }
+    }
+    pub fn set_listener_weight(&self, listener: i32, weight: f32) -> Result<(), Error> {
+        let result = Studio_System_SetListenerWeight(&self.opaque, listener, weight);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_System_SetListenerWeight", err)),
+        }
+    }
+    pub fn get_listener_weight(&self, listener: i32) -> Result<f32, Error> {
+        let result = Studio_System_GetListenerWeight(&self.opaque, listener);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_System_GetListenerWeight", err)),
+        }
+    }
     pub fn set_listener_attributes(
         &self,
         index: i32,
@@ -118,6 +204,13 @@ impl Studio {
             err => Err(err_fmod!("Studio_System_Update", err)),
         }
     }
+    pub fn get_listener_attributes(&self, index: i32) -> Result<(Attributes3d, Vector), Error> {
+        let result = Studio_System_GetListenerAttributes(&self.opaque, index);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok((result.1, result.2)),
+            err => Err(err_fmod!("Studio_System_GetListenerAttributes", err)),
+        }
+    }
     pub fn update(&self) -> Result<(), Error> {
         let result = Studio_System_Update(&self.opaque);
         match FMODResult::from(result.0) {
@@ -125,6 +218,27 @@ impl Studio {
             err => Err(err_fmod!("Studio_System_Update", err)),
         }
     }
+    pub fn flush_commands(&self) -> Result<(), Error> {
+        let result = Studio_System_FlushCommands(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_System_FlushCommands", err)),
+        }
+    }
+    pub fn flush_sample_loading(&self) -> Result<(), Error> {
+        let result = Studio_System_FlushSampleLoading(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_System_FlushSampleLoading", err)),
+        }
+    }
+    pub fn get_core_system(&self) -> Result<System, Error> {
+        let result = Studio_System_GetCoreSystem(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(System { opaque: result.1 }),
+            err => Err(err_fmod!("Studio_System_GetCoreSystem", err)),
+        }
+    }
 }
 #[wasm_bindgen]
 extern "C" {
@@ -142,10 +256,14 @@ extern "C" {
     fn Studio_System_LoadBankMemory(studio: &JsValue, buffer: &[u8], flags: u32)
         -> JsValueJSResult;
     #[wasm_bindgen]
+    fn Studio_System_Release(studio: &JsValue) -> JSResult;
+    #[wasm_bindgen]
     fn Studio_System_UnloadAll(studio: &JsValue) -> JSResult;
     #[wasm_bindgen]
     fn Studio_System_GetEvent(studio: &JsValue, path: &str) -> JsValueJSResult;
     #[wasm_bindgen]
+    fn Studio_System_GetEventByID(studio: &JsValue, id: &str) -> JsValueJSResult;
+    #[wasm_bindgen]
     fn Studio_System_GetBus(studio: &JsValue, path: &str) -> JsValueJSResult;
     #[wasm_bindgen]
     fn Studio_System_SetParameterByName(
@@ -155,6 +273,31 @@ extern "C" {
         ignore_seek_speed: bool,
     ) -> JSResult;
     #[wasm_bindgen]
+    fn Studio_System_SetParameterByID(
+        studio: &JsValue,
+        id: ParameterId,
+        value: f32,
+        ignore_seek_speed: bool,
+    ) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_System_GetParameterDescriptionByName(
+        studio: &JsValue,
+        name: &str,
+    ) -> ParameterDescriptionJSResult;
+    #[wasm_bindgen]
+    fn Studio_System_SetNumListeners(studio: &JsValue, numlisteners: i32) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_System_GetNumListeners(studio: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_System_SetListenerWeight(studio: &JsValue, listener: i32, weight: f32) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_System_GetListenerWeight(studio: &JsValue, listener: i32) -> F32JSResult;
+    #[wasm_bindgen]
+    fn Studio_System_GetListenerAttributes(
+        studio: &JsValue,
+        index: i32,
+    ) -> Attributes3dVectorJSResult;
+    #[wasm_bindgen]
     fn Studio_System_SetListenerAttributes(
         studio: &JsValue,
         index: i32,
@@ -163,6 +306,202 @@ extern "C" {
     ) -> JSResult;
     #[wasm_bindgen]
     fn Studio_System_Update(studio: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_System_FlushCommands(studio: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_System_FlushSampleLoading(studio: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_System_GetCoreSystem(studio: &JsValue) -> JsValueJSResult;
+}
+
+// System wrapper and binding. Driver selection isn't bound here, as the
+// browser is the one deciding which output device is used.
+#[derive(Debug, Clone)]
+pub struct System {
+    opaque: JsValue,
+}
+impl System {
+    pub fn set_output(&self, output: OutputType) -> Result<(), Error> {
+        let result = System_SetOutput(&self.opaque, OutputType::from(output));
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("System_SetOutput", err)),
+        }
+    }
+    pub fn set_software_format(
+        &self,
+        samplerate: i32,
+        speakermode: SpeakerMode,
+        numrawspeakers: i32,
+    ) -> Result<(), Error> {
+        let result =
+            System_SetSoftwareFormat(&self.opaque, samplerate, speakermode, numrawspeakers);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("System_SetSoftwareFormat", err)),
+        }
+    }
+    pub fn get_software_format(&self) -> Result<(i32, SpeakerMode, i32), Error> {
+        let result = System_GetSoftwareFormat(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok((result.1, SpeakerMode::try_from(result.2)?, result.3)),
+            err => Err(err_fmod!("System_GetSoftwareFormat", err)),
+        }
+    }
+    pub fn set_dsp_buffer_size(&self, bufferlength: u32, numbuffers: i32) -> Result<(), Error> {
+        let result = System_SetDSPBufferSize(&self.opaque, bufferlength, numbuffers);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("System_SetDSPBufferSize", err)),
+        }
+    }
+    pub fn get_dsp_buffer_size(&self) -> Result<(u32, i32), Error> {
+        let result = System_GetDSPBufferSize(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok((result.1, result.2)),
+            err => Err(err_fmod!("System_GetDSPBufferSize", err)),
+        }
+    }
+    pub fn mixer_suspend(&self) -> Result<(), Error> {
+        let result = System_MixerSuspend(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("System_MixerSuspend", err)),
+        }
+    }
+    pub fn mixer_resume(&self) -> Result<(), Error> {
+        let result = System_MixerResume(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("System_MixerResume", err)),
+        }
+    }
+    pub fn set_3d_settings(
+        &self,
+        doppler_scale: f32,
+        distance_factor: f32,
+        rolloff_scale: f32,
+    ) -> Result<(), Error> {
+        let result =
+            System_Set3DSettings(&self.opaque, doppler_scale, distance_factor, rolloff_scale);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("System_Set3DSettings", err)),
+        }
+    }
+    pub fn set_reverb_properties(
+        &self,
+        instance: i32,
+        prop: ReverbProperties,
+    ) -> Result<(), Error> {
+        let result = System_SetReverbProperties(&self.opaque, instance, prop);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("System_SetReverbProperties", err)),
+        }
+    }
+    pub fn get_version(&self) -> Result<u32, Error> {
+        let result = System_GetVersion(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("System_GetVersion", err)),
+        }
+    }
+    pub fn get_3d_settings(&self) -> Result<(f32, f32, f32), Error> {
+        let result = System_Get3DSettings(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok((result.1, result.2, result.3)),
+            err => Err(err_fmod!("System_Get3DSettings", err)),
+        }
+    }
+    pub fn get_master_channel_group(&self) -> Result<ChannelGroup, Error> {
+        let result = System_GetMasterChannelGroup(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(ChannelGroup { opaque: result.1 }),
+            err => Err(err_fmod!("System_GetMasterChannelGroup", err)),
+        }
+    }
+    pub fn create_dsp_by_type(&self, dsp_type: DspType) -> Result<Dsp, Error> {
+        let result = System_CreateDSPByType(&self.opaque, dsp_type);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(Dsp { opaque: result.1 }),
+            err => Err(err_fmod!("System_CreateDSPByType", err)),
+        }
+    }
+    // libfmod's create_sound takes a string, so memory is our own entry point.
+    // FMOD_OPENMEMORY has to be in the mode, which makes FMOD copy the data.
+    pub fn create_sound_from_memory(&self, data: &[u8], mode: u32) -> Result<Sound, Error> {
+        let result = System_CreateSound(&self.opaque, data, mode);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(Sound { opaque: result.1 }),
+            err => Err(err_fmod!("System_CreateSound", err)),
+        }
+    }
+    pub fn play_sound(
+        &self,
+        sound: Sound,
+        channelgroup: Option<ChannelGroup>,
+        paused: bool,
+    ) -> Result<Channel, Error> {
+        let channelgroup = channelgroup.map_or(JsValue::NULL, |group| group.opaque);
+        let result = System_PlaySound(&self.opaque, &sound.opaque, &channelgroup, paused);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(Channel { opaque: result.1 }),
+            err => Err(err_fmod!("System_PlaySound", err)),
+        }
+    }
+}
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen]
+    fn System_SetOutput(system: &JsValue, output: OutputType) -> JSResult;
+    #[wasm_bindgen]
+    fn System_SetSoftwareFormat(
+        system: &JsValue,
+        samplerate: i32,
+        speakermode: SpeakerMode,
+        numrawspeakers: i32,
+    ) -> JSResult;
+    #[wasm_bindgen]
+    fn System_GetSoftwareFormat(system: &JsValue) -> I32I32I32JSResult;
+    #[wasm_bindgen]
+    fn System_SetDSPBufferSize(system: &JsValue, bufferlength: u32, numbuffers: i32) -> JSResult;
+    #[wasm_bindgen]
+    fn System_GetDSPBufferSize(system: &JsValue) -> U32I32JSResult;
+    #[wasm_bindgen]
+    fn System_MixerSuspend(system: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn System_MixerResume(system: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn System_Set3DSettings(
+        system: &JsValue,
+        doppler_scale: f32,
+        distance_factor: f32,
+        rolloff_scale: f32,
+    ) -> JSResult;
+    #[wasm_bindgen]
+    fn System_Get3DSettings(system: &JsValue) -> F32F32F32JSResult;
+    #[wasm_bindgen]
+    fn System_GetMasterChannelGroup(system: &JsValue) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn System_CreateDSPByType(system: &JsValue, dsp_type: DspType) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn System_CreateSound(system: &JsValue, data: &[u8], mode: u32) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn System_PlaySound(
+        system: &JsValue,
+        sound: &JsValue,
+        channelgroup: &JsValue,
+        paused: bool,
+    ) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn System_GetVersion(system: &JsValue) -> U32JSResult;
+    #[wasm_bindgen]
+    fn System_SetReverbProperties(
+        system: &JsValue,
+        instance: i32,
+        prop: ReverbProperties,
+    ) -> JSResult;
 }
 
 // Bank wrapper and binding
@@ -189,6 +528,37 @@ impl Bank {
             err => Err(err_fmod!("Studio_Bank_GetEventCount", err)),
         }
     }
+    pub fn get_loading_state(&self) -> Result<LoadingState, Error> {
+        let result = Studio_Bank_GetLoadingState(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => LoadingState::try_from(result.1),
+            err => Err(err_fmod!("Studio_Bank_GetLoadingState", err)),
+        }
+    }
+    pub fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+        let result = Studio_Bank_GetSampleLoadingState(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => LoadingState::try_from(result.1),
+            err => Err(err_fmod!("Studio_Bank_GetSampleLoadingState", err)),
+        }
+    }
+    pub fn get_id(&self) -> Result<Guid, Error> {
+        let result = Studio_Bank_GetID(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Guid::from_js_string(&result.1),
+            err => Err(err_fmod!("Studio_Bank_GetID", err)),
+        }
+    }
+    pub fn get_path(&self) -> Result<String, Error> {
+        let result = Studio_Bank_GetPath(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_Bank_GetPath", err)),
+        }
+    }
+    pub fn is_valid(&self) -> bool {
+        Studio_Bank_IsValid(&self.opaque)
+    }
 }
 #[wasm_bindgen]
 extern "C" {
@@ -196,6 +566,16 @@ extern "C" {
     fn Studio_Bank_GetEventList(bank: &JsValue, capacity: i32) -> JsValueVecJSResult;
     #[wasm_bindgen]
     fn Studio_Bank_GetEventCount(bank: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_Bank_GetLoadingState(bank: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_Bank_GetSampleLoadingState(bank: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_Bank_GetID(bank: &JsValue) -> StringJSResult;
+    #[wasm_bindgen]
+    fn Studio_Bank_GetPath(bank: &JsValue) -> StringJSResult;
+    #[wasm_bindgen]
+    fn Studio_Bank_IsValid(bank: &JsValue) -> bool;
 }
 
 // EventDescription wrapper and binding
@@ -225,90 +605,237 @@ impl EventDescription {
             err => Err(err_fmod!("Studio_EventDescription_GetInstanceCount", err)),
         }
     }
-}
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen]
-    fn Studio_EventDescription_GetPath(description: &JsValue) -> StringJSResult;
-    #[wasm_bindgen]
-    fn Studio_EventDescription_CreateInstance(description: &JsValue) -> JsValueJSResult;
-    #[wasm_bindgen]
-    fn Studio_EventDescription_GetInstanceCount(description: &JsValue) -> I32JSResult;
-}
-
-// EventInstance wrapper and binding
-#[derive(Debug, Clone)]
-pub struct EventInstance {
-    opaque: JsValue,
-}
-impl EventInstance {
-    pub fn start(&self) -> Result<(), Error> {
-        let result = Studio_EventInstance_Start(&self.opaque);
+    pub fn load_sample_data(&self) -> Result<(), Error> {
+        let result = Studio_EventDescription_LoadSampleData(&self.opaque);
         match FMODResult::from(result.0) {
             FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_Start", err)),
+            err => Err(err_fmod!("Studio_EventDescription_LoadSampleData", err)),
         }
     }
-    pub fn release(&self) -> Result<(), Error> {
-        let result = Studio_EventInstance_Release(&self.opaque);
+    pub fn unload_sample_data(&self) -> Result<(), Error> {
+        let result = Studio_EventDescription_UnloadSampleData(&self.opaque);
         match FMODResult::from(result.0) {
             FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_Release", err)),
+            err => Err(err_fmod!("Studio_EventDescription_UnloadSampleData", err)),
         }
     }
-    pub fn get_3d_attributes(&self) -> Result<Attributes3d, Error> {
-        let result = Studio_EventInstance_Get3DAttributes(&self.opaque);
+    pub fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+        let result = Studio_EventDescription_GetSampleLoadingState(&self.opaque);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(Attributes3d::from(result.1)),
-            err => Err(err_fmod!("Studio_EventInstance_Get3DAttributes", err)),
+            FMODResult::Ok => LoadingState::try_from(result.1),
+            err => Err(err_fmod!(
+                "Studio_EventDescription_GetSampleLoadingState",
+                err
+            )),
         }
     }
-    pub fn set_3d_attributes(&self, attributes: Attributes3d) -> Result<(), Error> {
-        let result =
-            Studio_EventInstance_Set3DAttributes(&self.opaque, Attributes3d::from(attributes));
+    pub fn get_instance_list(&self, capacity: i32) -> Result<Vec<EventInstance>, Error> {
+        let result = Studio_EventDescription_GetInstanceList(&self.opaque, capacity);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_Set3DAttributes", err)),
+            FMODResult::Ok => Ok(result
+                .1
+                .into_iter()
+                .map(|opaque| EventInstance { opaque })
+                .collect()),
+            err => Err(err_fmod!("Studio_EventDescription_GetInstanceList", err)),
         }
     }
-    pub fn get_pitch(&self) -> Result<(f32, f32), Error> {
-        let result = Studio_EventInstance_GetPitch(&self.opaque);
+    pub fn is_3d(&self) -> Result<bool, Error> {
+        let result = Studio_EventDescription_Is3D(&self.opaque);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok((result.1, result.2)),
-            err => Err(err_fmod!("Studio_EventInstance_GetPitch", err)),
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_EventDescription_Is3D", err)),
         }
     }
-    pub fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
-        let result = Studio_EventInstance_SetPitch(&self.opaque, pitch);
+    pub fn is_oneshot(&self) -> Result<bool, Error> {
+        let result = Studio_EventDescription_IsOneshot(&self.opaque);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_SetPitch", err)),
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_EventDescription_IsOneshot", err)),
         }
     }
-    pub fn get_property(&self, index: EventProperty) -> Result<f32, Error> {
-        let result = Studio_EventInstance_GetProperty(&self.opaque, EventProperty::from(index));
+    pub fn is_valid(&self) -> bool {
+        Studio_EventDescription_IsValid(&self.opaque)
+    }
+    pub fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        let result = Studio_EventDescription_GetMinMaxDistance(&self.opaque);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(result.1),
-            err => Err(err_fmod!("Studio_EventInstance_GetProperty", err)),
+            FMODResult::Ok => Ok((result.1, result.2)),
+            err => Err(err_fmod!("Studio_EventDescription_GetMinMaxDistance", err)),
         }
     }
-    pub fn set_property(&self, index: EventProperty, value: f32) -> Result<(), Error> {
-        let result =
-            Studio_EventInstance_SetProperty(&self.opaque, EventProperty::from(index), value);
+    pub fn get_parameter_description_count(&self) -> Result<i32, Error> {
+        let result = Studio_EventDescription_GetParameterDescriptionCount(&self.opaque);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_SetProperty", err)),
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!(
+                "Studio_EventDescription_GetParameterDescriptionCount",
+                err
+            )),
         }
     }
-    pub fn get_timeline_position(&self) -> Result<i32, Error> {
-        let result = Studio_EventInstance_GetTimelinePosition(&self.opaque);
+    pub fn get_parameter_description_by_index(
+        &self,
+        index: i32,
+    ) -> Result<ParameterDescription, Error> {
+        let result = Studio_EventDescription_GetParameterDescriptionByIndex(&self.opaque, index);
         match FMODResult::from(result.0) {
             FMODResult::Ok => Ok(result.1),
-            err => Err(err_fmod!("Studio_EventInstance_GetTimelinePosition", err)),
+            err => Err(err_fmod!(
+                "Studio_EventDescription_GetParameterDescriptionByIndex",
+                err
+            )),
         }
     }
-    pub fn set_timeline_position(&self, position: i32) -> Result<(), Error> {
-        let result = Studio_EventInstance_SetTimelinePosition(&self.opaque, position);
+    pub fn get_user_property(&self, name: &str) -> Result<UserProperty, Error> {
+        let result = Studio_EventDescription_GetUserProperty(&self.opaque, name);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => UserProperty::from_js(result.1, result.2, result.3),
+            err => Err(err_fmod!("Studio_EventDescription_GetUserProperty", err)),
+        }
+    }
+    pub fn get_user_property_count(&self) -> Result<i32, Error> {
+        let result = Studio_EventDescription_GetUserPropertyCount(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!(
+                "Studio_EventDescription_GetUserPropertyCount",
+                err
+            )),
+        }
+    }
+    pub fn get_user_property_by_index(&self, index: i32) -> Result<UserProperty, Error> {
+        let result = Studio_EventDescription_GetUserPropertyByIndex(&self.opaque, index);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => UserProperty::from_js(result.1, result.2, result.3),
+            err => Err(err_fmod!(
+                "Studio_EventDescription_GetUserPropertyByIndex",
+                err
+            )),
+        }
+    }
+}
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetPath(description: &JsValue) -> StringJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_CreateInstance(description: &JsValue) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetInstanceCount(description: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_LoadSampleData(description: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_UnloadSampleData(description: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetSampleLoadingState(description: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetInstanceList(
+        description: &JsValue,
+        capacity: i32,
+    ) -> JsValueVecJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_Is3D(description: &JsValue) -> BoolJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_IsOneshot(description: &JsValue) -> BoolJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_IsValid(description: &JsValue) -> bool;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetMinMaxDistance(description: &JsValue) -> F32F32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetParameterDescriptionCount(description: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetParameterDescriptionByIndex(
+        description: &JsValue,
+        index: i32,
+    ) -> ParameterDescriptionJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetUserProperty(
+        description: &JsValue,
+        name: &str,
+    ) -> UserPropertyJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetUserPropertyCount(description: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventDescription_GetUserPropertyByIndex(
+        description: &JsValue,
+        index: i32,
+    ) -> UserPropertyJSResult;
+}
+
+// EventInstance wrapper and binding
+#[derive(Debug, Clone)]
+pub struct EventInstance {
+    opaque: JsValue,
+}
+impl EventInstance {
+    pub fn start(&self) -> Result<(), Error> {
+        let result = Studio_EventInstance_Start(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_Start", err)),
+        }
+    }
+    pub fn release(&self) -> Result<(), Error> {
+        let result = Studio_EventInstance_Release(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_Release", err)),
+        }
+    }
+    pub fn get_3d_attributes(&self) -> Result<Attributes3d, Error> {
+        let result = Studio_EventInstance_Get3DAttributes(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(Attributes3d::from(result.1)),
+            err => Err(err_fmod!("Studio_EventInstance_Get3DAttributes", err)),
+        }
+    }
+    pub fn set_3d_attributes(&self, attributes: Attributes3d) -> Result<(), Error> {
+        let result =
+            Studio_EventInstance_Set3DAttributes(&self.opaque, Attributes3d::from(attributes));
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_Set3DAttributes", err)),
+        }
+    }
+    pub fn get_pitch(&self) -> Result<(f32, f32), Error> {
+        let result = Studio_EventInstance_GetPitch(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok((result.1, result.2)),
+            err => Err(err_fmod!("Studio_EventInstance_GetPitch", err)),
+        }
+    }
+    pub fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        let result = Studio_EventInstance_SetPitch(&self.opaque, pitch);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_SetPitch", err)),
+        }
+    }
+    pub fn get_property(&self, index: EventProperty) -> Result<f32, Error> {
+        let result = Studio_EventInstance_GetProperty(&self.opaque, EventProperty::from(index));
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_EventInstance_GetProperty", err)),
+        }
+    }
+    pub fn set_property(&self, index: EventProperty, value: f32) -> Result<(), Error> {
+        let result =
+            Studio_EventInstance_SetProperty(&self.opaque, EventProperty::from(index), value);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_SetProperty", err)),
+        }
+    }
+    pub fn get_timeline_position(&self) -> Result<i32, Error> {
+        let result = Studio_EventInstance_GetTimelinePosition(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_EventInstance_GetTimelinePosition", err)),
+        }
+    }
+    pub fn set_timeline_position(&self, position: i32) -> Result<(), Error> {
+        let result = Studio_EventInstance_SetTimelinePosition(&self.opaque, position);
         match FMODResult::from(result.0) {
             FMODResult::Ok => Ok(()),
             err => Err(err_fmod!("Studio_EventInstance_SetTimelinePosition", err)),
@@ -325,140 +852,513 @@ impl EventInstance {
         let result = Studio_EventInstance_SetVolume(&self.opaque, volume);
         match FMODResult::from(result.0) {
             FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_SetVolume", err)),
-        }
-    }
-    pub fn is_virtual(&self) -> Result<bool, Error> {
-        let result = Studio_EventInstance_IsVirtual(&self.opaque);
-        match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(result.1),
-            err => Err(err_fmod!("Studio_EventInstance_IsVirtual", err)),
+            err => Err(err_fmod!("Studio_EventInstance_SetVolume", err)),
+        }
+    }
+    pub fn is_virtual(&self) -> Result<bool, Error> {
+        let result = Studio_EventInstance_IsVirtual(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_EventInstance_IsVirtual", err)),
+        }
+    }
+    pub fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        let result = Studio_EventInstance_GetMemoryUsage(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_EventInstance_GetMemoryUsage", err)),
+        }
+    }
+    pub fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+        let result = Studio_EventInstance_GetParameterByName(&self.opaque, name);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok((result.1, result.2)),
+            err => Err(err_fmod!("Studio_EventInstance_GetParameterByName", err)),
+        }
+    }
+    pub fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignore_seek_speed: bool,
+    ) -> Result<(), Error> {
+        let result =
+            Studio_EventInstance_SetParameterByName(&self.opaque, name, value, ignore_seek_speed);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_SetParameterByName", err)),
+        }
+    }
+    pub fn stop(&self, mode: StopMode) -> Result<(), Error> {
+        let result = Studio_EventInstance_Stop(&self.opaque, StopMode::from(mode));
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_Stop", err)),
+        }
+    }
+    pub fn get_paused(&self) -> Result<bool, Error> {
+        let result = Studio_EventInstance_GetPaused(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_EventInstance_GetPaused", err)),
+        }
+    }
+    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        let result = Studio_EventInstance_SetPaused(&self.opaque, paused);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_SetPaused", err)),
+        }
+    }
+    pub fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+        let result = Studio_EventInstance_GetPlaybackState(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => PlaybackState::try_from(result.1),
+            err => Err(err_fmod!("Studio_EventInstance_GetPlaybackState", err)),
+        }
+    }
+    pub fn is_valid(&self) -> bool {
+        Studio_EventInstance_IsValid(&self.opaque)
+    }
+    pub fn get_description(&self) -> Result<EventDescription, Error> {
+        let result = Studio_EventInstance_GetDescription(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(EventDescription { opaque: result.1 }),
+            err => Err(err_fmod!("Studio_EventInstance_GetDescription", err)),
+        }
+    }
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        // pointers are 32 bits on wasm32
+        let result = Studio_EventInstance_SetUserData(&self.opaque, userdata as usize as u32);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_SetUserData", err)),
+        }
+    }
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        let result = Studio_EventInstance_GetUserData(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1 as usize as *mut c_void),
+            err => Err(err_fmod!("Studio_EventInstance_GetUserData", err)),
+        }
+    }
+    pub fn key_off(&self) -> Result<(), Error> {
+        let result = Studio_EventInstance_KeyOff(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_KeyOff", err)),
+        }
+    }
+    pub fn set_listener_mask(&self, mask: u32) -> Result<(), Error> {
+        let result = Studio_EventInstance_SetListenerMask(&self.opaque, mask);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_EventInstance_SetListenerMask", err)),
+        }
+    }
+    pub fn get_listener_mask(&self) -> Result<u32, Error> {
+        let result = Studio_EventInstance_GetListenerMask(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_EventInstance_GetListenerMask", err)),
+        }
+    }
+    pub fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        let result = Studio_EventInstance_GetChannelGroup(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(ChannelGroup { opaque: result.1 }),
+            err => Err(err_fmod!("Studio_EventInstance_GetChannelGroup", err)),
+        }
+    }
+}
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen]
+    fn Studio_EventInstance_Start(instance: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_Release(instance: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_Get3DAttributes(instance: &JsValue) -> Attributes3dJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_Set3DAttributes(
+        instance: &JsValue,
+        attributes: Attributes3d,
+    ) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetPitch(instance: &JsValue) -> F32F32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_SetPitch(instance: &JsValue, pitch: f32) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetProperty(instance: &JsValue, index: EventProperty) -> F32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_SetProperty(
+        instance: &JsValue,
+        index: EventProperty,
+        value: f32,
+    ) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetTimelinePosition(instance: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_SetTimelinePosition(instance: &JsValue, position: i32) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetVolume(instance: &JsValue) -> F32F32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_SetVolume(instance: &JsValue, volume: f32) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_IsVirtual(instance: &JsValue) -> BoolJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetMemoryUsage(instance: &JsValue) -> MemoryUsageJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetParameterByName(instance: &JsValue, name: &str) -> F32F32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_SetParameterByName(
+        instance: &JsValue,
+        name: &str,
+        value: f32,
+        ignore_seek_speed: bool,
+    ) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_Stop(instance: &JsValue, stop_mode: StopMode) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_SetPaused(instance: &JsValue, paused: bool) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetPaused(instance: &JsValue) -> BoolJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetPlaybackState(instance: &JsValue) -> I32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_IsValid(instance: &JsValue) -> bool;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetDescription(instance: &JsValue) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetChannelGroup(instance: &JsValue) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_KeyOff(instance: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_SetListenerMask(instance: &JsValue, mask: u32) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetListenerMask(instance: &JsValue) -> U32JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_SetUserData(instance: &JsValue, userdata: u32) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_EventInstance_GetUserData(instance: &JsValue) -> U32JSResult;
+}
+
+// Bus wrapper and binding
+#[derive(Debug, Clone)]
+pub struct Bus {
+    opaque: JsValue,
+}
+impl Bus {
+    pub fn stop_all_events(&self, mode: StopMode) -> Result<(), Error> {
+        let result = Studio_Bus_StopAllEvents(&self.opaque, StopMode::from(mode));
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_Bus_StopAllEvents", err)),
+        }
+    }
+    pub fn lock_channel_group(&self) -> Result<(), Error> {
+        let result = Studio_Bus_LockChannelGroup(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_Bus_LockChannelGroup", err)),
+        }
+    }
+    pub fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        let result = Studio_Bus_GetChannelGroup(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(ChannelGroup { opaque: result.1 }),
+            err => Err(err_fmod!("Studio_Bus_GetChannelGroup", err)),
+        }
+    }
+    pub fn get_mute(&self) -> Result<bool, Error> {
+        let result = Studio_Bus_GetMute(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_Bus_GetMute", err)),
+        }
+    }
+    pub fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        let result = Studio_Bus_SetMute(&self.opaque, mute);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_Bus_SetMute", err)),
+        }
+    }
+    pub fn get_volume(&self) -> Result<(f32, f32), Error> {
+        let result = Studio_Bus_GetVolume(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok((result.1, result.2)),
+            err => Err(err_fmod!("Studio_Bus_GetVolume", err)),
+        }
+    }
+    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        let result = Studio_Bus_SetVolume(&self.opaque, volume);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_Bus_SetVolume", err)),
+        }
+    }
+    pub fn get_paused(&self) -> Result<bool, Error> {
+        let result = Studio_Bus_GetPaused(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Studio_Bus_GetPaused", err)),
+        }
+    }
+    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        let result = Studio_Bus_SetPaused(&self.opaque, paused);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Studio_Bus_SetPaused", err)),
+        }
+    }
+}
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen]
+    fn Studio_Bus_StopAllEvents(bus: &JsValue, stop_mode: StopMode) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_Bus_LockChannelGroup(bus: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_Bus_GetChannelGroup(bus: &JsValue) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn Studio_Bus_GetMute(bus: &JsValue) -> BoolJSResult;
+    #[wasm_bindgen]
+    fn Studio_Bus_SetMute(bus: &JsValue, mute: bool) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_Bus_GetVolume(bus: &JsValue) -> F32F32JSResult;
+    #[wasm_bindgen]
+    fn Studio_Bus_SetVolume(bus: &JsValue, volume: f32) -> JSResult;
+    #[wasm_bindgen]
+    fn Studio_Bus_GetPaused(bus: &JsValue) -> BoolJSResult;
+    #[wasm_bindgen]
+    fn Studio_Bus_SetPaused(bus: &JsValue, paused: bool) -> JSResult;
+}
+
+// ChannelGroup wrapper and binding
+#[derive(Debug, Clone)]
+pub struct ChannelGroup {
+    opaque: JsValue,
+}
+impl ChannelGroup {
+    pub fn get_dsp(&self, index: i32) -> Result<Dsp, Error> {
+        let result = ChannelGroup_GetDSP(&self.opaque, index);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(Dsp { opaque: result.1 }),
+            err => Err(err_fmod!("ChannelGroup_GetDSP", err)),
+        }
+    }
+    pub fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error> {
+        let result = ChannelGroup_AddDSP(&self.opaque, index, &dsp.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("ChannelGroup_AddDSP", err)),
+        }
+    }
+    pub fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error> {
+        let result = ChannelGroup_RemoveDSP(&self.opaque, &dsp.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("ChannelGroup_RemoveDSP", err)),
+        }
+    }
+    pub fn get_system_object(&self) -> Result<System, Error> {
+        let result = ChannelGroup_GetSystemObject(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(System { opaque: result.1 }),
+            err => Err(err_fmod!("ChannelGroup_GetSystemObject", err)),
+        }
+    }
+    pub fn set_3d_cone_settings(
+        &self,
+        inside_cone_angle: f32,
+        outside_cone_angle: f32,
+        outside_volume: f32,
+    ) -> Result<(), Error> {
+        let result = ChannelGroup_Set3DConeSettings(
+            &self.opaque,
+            inside_cone_angle,
+            outside_cone_angle,
+            outside_volume,
+        );
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("ChannelGroup_Set3DConeSettings", err)),
+        }
+    }
+    pub fn set_3d_occlusion(
+        &self,
+        direct_occlusion: f32,
+        reverb_occlusion: f32,
+    ) -> Result<(), Error> {
+        let result = ChannelGroup_Set3DOcclusion(&self.opaque, direct_occlusion, reverb_occlusion);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("ChannelGroup_Set3DOcclusion", err)),
+        }
+    }
+}
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen]
+    fn ChannelGroup_GetDSP(channel_group: &JsValue, index: i32) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn ChannelGroup_AddDSP(channel_group: &JsValue, index: i32, dsp: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn ChannelGroup_RemoveDSP(channel_group: &JsValue, dsp: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn ChannelGroup_GetSystemObject(channel_group: &JsValue) -> JsValueJSResult;
+    #[wasm_bindgen]
+    fn ChannelGroup_Set3DConeSettings(
+        channel_group: &JsValue,
+        inside_cone_angle: f32,
+        outside_cone_angle: f32,
+        outside_volume: f32,
+    ) -> JSResult;
+    #[wasm_bindgen]
+    fn ChannelGroup_Set3DOcclusion(
+        channel_group: &JsValue,
+        direct_occlusion: f32,
+        reverb_occlusion: f32,
+    ) -> JSResult;
+}
+
+// Dsp wrapper and binding
+#[derive(Debug, Clone)]
+pub struct Dsp {
+    opaque: JsValue,
+}
+impl Dsp {
+    pub fn set_metering_enabled(
+        &self,
+        input_enabled: bool,
+        output_enabled: bool,
+    ) -> Result<(), Error> {
+        let result = DSP_SetMeteringEnabled(&self.opaque, input_enabled, output_enabled);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("DSP_SetMeteringEnabled", err)),
         }
     }
-    pub fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
-        let result = Studio_EventInstance_GetParameterByName(&self.opaque, name);
+    pub fn get_metering_info(&self) -> Result<(DspMeteringInfo, DspMeteringInfo), Error> {
+        let result = DSP_GetMeteringInfo(&self.opaque);
         match FMODResult::from(result.0) {
             FMODResult::Ok => Ok((result.1, result.2)),
-            err => Err(err_fmod!("Studio_EventInstance_GetParameterByName", err)),
+            err => Err(err_fmod!("DSP_GetMeteringInfo", err)),
         }
     }
-    pub fn set_parameter_by_name(
-        &self,
-        name: &str,
-        value: f32,
-        ignore_seek_speed: bool,
-    ) -> Result<(), Error> {
-        let result =
-            Studio_EventInstance_SetParameterByName(&self.opaque, name, value, ignore_seek_speed);
+    pub fn get_type(&self) -> Result<DspType, Error> {
+        let result = DSP_GetType(&self.opaque);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_SetParameterByName", err)),
+            FMODResult::Ok => DspType::try_from(result.1),
+            err => Err(err_fmod!("DSP_GetType", err)),
         }
     }
-    pub fn stop(&self, mode: StopMode) -> Result<(), Error> {
-        let result = Studio_EventInstance_Stop(&self.opaque, StopMode::from(mode));
+    pub fn set_parameter_float(&self, index: i32, value: f32) -> Result<(), Error> {
+        let result = DSP_SetParameterFloat(&self.opaque, index, value);
         match FMODResult::from(result.0) {
             FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_Stop", err)),
+            err => Err(err_fmod!("DSP_SetParameterFloat", err)),
         }
     }
-    pub fn get_paused(&self) -> Result<bool, Error> {
-        let result = Studio_EventInstance_GetPaused(&self.opaque);
+    pub fn release(&self) -> Result<(), Error> {
+        let result = DSP_Release(&self.opaque);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(result.1),
-            err => Err(err_fmod!("Studio_EventInstance_GetPaused", err)),
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("DSP_Release", err)),
         }
     }
-    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
-        let result = Studio_EventInstance_SetPaused(&self.opaque, paused);
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        // pointers are 32 bits on wasm32
+        let result = DSP_SetUserData(&self.opaque, userdata as usize as u32);
         match FMODResult::from(result.0) {
             FMODResult::Ok => Ok(()),
-            err => Err(err_fmod!("Studio_EventInstance_SetPaused", err)),
+            err => Err(err_fmod!("DSP_SetUserData", err)),
         }
     }
-    pub fn get_playback_state(&self) -> Result<PlaybackState, Error> {
-        let result = Studio_EventInstance_GetPlaybackState(&self.opaque);
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        let result = DSP_GetUserData(&self.opaque);
         match FMODResult::from(result.0) {
-            FMODResult::Ok => Ok(match result.1 {
-                PlaybackState::Playing => PlaybackState::Playing,
-                PlaybackState::Sustaining => PlaybackState::Sustaining,
-                PlaybackState::Stopped => PlaybackState::Stopped,
-                PlaybackState::Starting => PlaybackState::Starting,
-                PlaybackState::Stopping => PlaybackState::Stopping,
-            }),
-            err => Err(err_fmod!("Studio_EventInstance_GetPitch", err)),
+            FMODResult::Ok => Ok(result.1 as usize as *mut c_void),
+            err => Err(err_fmod!("DSP_GetUserData", err)),
         }
     }
 }
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen]
-    fn Studio_EventInstance_Start(instance: &JsValue) -> JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_Release(instance: &JsValue) -> JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_Get3DAttributes(instance: &JsValue) -> Attributes3dJSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_Set3DAttributes(
-        instance: &JsValue,
-        attributes: Attributes3d,
-    ) -> JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_GetPitch(instance: &JsValue) -> F32F32JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_SetPitch(instance: &JsValue, pitch: f32) -> JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_GetProperty(instance: &JsValue, index: EventProperty) -> F32JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_SetProperty(
-        instance: &JsValue,
-        index: EventProperty,
-        value: f32,
-    ) -> JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_GetTimelinePosition(instance: &JsValue) -> I32JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_SetTimelinePosition(instance: &JsValue, position: i32) -> JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_GetVolume(instance: &JsValue) -> F32F32JSResult;
-    #[wasm_bindgen]
-    fn Studio_EventInstance_SetVolume(instance: &JsValue, volume: f32) -> JSResult;
+    fn DSP_SetMeteringEnabled(dsp: &JsValue, input_enabled: bool, output_enabled: bool)
+        -> JSResult;
     #[wasm_bindgen]
-    fn Studio_EventInstance_IsVirtual(instance: &JsValue) -> BoolJSResult;
+    fn DSP_GetMeteringInfo(dsp: &JsValue) -> DspMeteringInfoDspMeteringInfoJSResult;
     #[wasm_bindgen]
-    fn Studio_EventInstance_GetParameterByName(instance: &JsValue, name: &str) -> F32F32JSResult;
+    fn DSP_GetType(dsp: &JsValue) -> I32JSResult;
     #[wasm_bindgen]
-    fn Studio_EventInstance_SetParameterByName(
-        instance: &JsValue,
-        name: &str,
-        value: f32,
-        ignore_seek_speed: bool,
-    ) -> JSResult;
+    fn DSP_SetParameterFloat(dsp: &JsValue, index: i32, value: f32) -> JSResult;
     #[wasm_bindgen]
-    fn Studio_EventInstance_Stop(instance: &JsValue, stop_mode: StopMode) -> JSResult;
+    fn DSP_Release(dsp: &JsValue) -> JSResult;
     #[wasm_bindgen]
-    fn Studio_EventInstance_SetPaused(instance: &JsValue, paused: bool) -> JSResult;
+    fn DSP_SetUserData(dsp: &JsValue, userdata: u32) -> JSResult;
     #[wasm_bindgen]
-    fn Studio_EventInstance_GetPaused(instance: &JsValue) -> BoolJSResult;
+    fn DSP_GetUserData(dsp: &JsValue) -> U32JSResult;
+}
+
+// Sound wrapper and binding
+#[derive(Debug, Clone)]
+pub struct Sound {
+    opaque: JsValue,
+}
+impl Sound {
+    pub fn release(&self) -> Result<(), Error> {
+        let result = Sound_Release(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Sound_Release", err)),
+        }
+    }
+}
+#[wasm_bindgen]
+extern "C" {
     #[wasm_bindgen]
-    fn Studio_EventInstance_GetPlaybackState(instance: &JsValue) -> PlaybackStateJSResult;
+    fn Sound_Release(sound: &JsValue) -> JSResult;
 }
 
-// Bus wrapper and binding
+// Channel wrapper and binding
 #[derive(Debug, Clone)]
-pub struct Bus {
+pub struct Channel {
     opaque: JsValue,
 }
-impl Bus {
-    pub fn set_mute(&self, mute: bool) -> Result<(), ()> {
-        Studio_Bus_SetMute(&self.opaque, mute);
-        Ok(())
+impl Channel {
+    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        let result = Channel_SetVolume(&self.opaque, volume);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Channel_SetVolume", err)),
+        }
+    }
+    pub fn stop(&self) -> Result<(), Error> {
+        let result = Channel_Stop(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(()),
+            err => Err(err_fmod!("Channel_Stop", err)),
+        }
+    }
+    pub fn is_playing(&self) -> Result<bool, Error> {
+        let result = Channel_IsPlaying(&self.opaque);
+        match FMODResult::from(result.0) {
+            FMODResult::Ok => Ok(result.1),
+            err => Err(err_fmod!("Channel_IsPlaying", err)),
+        }
     }
 }
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen]
-    fn Studio_Bus_SetMute(bus: &JsValue, mute: bool);
+    fn Channel_SetVolume(channel: &JsValue, volume: f32) -> JSResult;
+    #[wasm_bindgen]
+    fn Channel_Stop(channel: &JsValue) -> JSResult;
+    #[wasm_bindgen]
+    fn Channel_IsPlaying(channel: &JsValue) -> BoolJSResult;
 }
 
 // Structs, bitflags and enums for libfmod parity
@@ -505,6 +1405,23 @@ impl From<Vector> for (f32, f32, f32) {
     }
 }
 
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReverbProperties {
+    pub decay_time: f32,
+    pub early_delay: f32,
+    pub late_delay: f32,
+    pub hf_reference: f32,
+    pub hf_decay_ratio: f32,
+    pub diffusion: f32,
+    pub density: f32,
+    pub low_shelf_frequency: f32,
+    pub low_shelf_gain: f32,
+    pub high_cut: f32,
+    pub early_late_mix: f32,
+    pub wet_level: f32,
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Attributes3d {
@@ -527,6 +1444,201 @@ impl Attributes3d {
     }
 }
 
+// Levels are arrays of 32 in FMOD, which bindgen can't give to JS, so these go
+// through the constructor as typed arrays instead.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DspMeteringInfo {
+    pub numsamples: i32,
+    #[wasm_bindgen(skip)]
+    pub peaklevel: [f32; 32],
+    #[wasm_bindgen(skip)]
+    pub rmslevel: [f32; 32],
+    pub numchannels: i16,
+}
+
+#[wasm_bindgen]
+impl DspMeteringInfo {
+    #[wasm_bindgen(constructor)]
+    pub fn new(numsamples: i32, peaklevel: &[f32], rmslevel: &[f32], numchannels: i16) -> Self {
+        let mut info = Self {
+            numsamples,
+            peaklevel: [0.0; 32],
+            rmslevel: [0.0; 32],
+            numchannels,
+        };
+        let peak_count = peaklevel.len().min(32);
+        info.peaklevel[..peak_count].copy_from_slice(&peaklevel[..peak_count]);
+        let rms_count = rmslevel.len().min(32);
+        info.rmslevel[..rms_count].copy_from_slice(&rmslevel[..rms_count]);
+        info
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MemoryUsage {
+    pub exclusive: i32,
+    pub inclusive: i32,
+    pub sampledata: i32,
+}
+
+#[wasm_bindgen]
+impl MemoryUsage {
+    #[wasm_bindgen(constructor)]
+    pub fn new(exclusive: i32, inclusive: i32, sampledata: i32) -> Self {
+        Self {
+            exclusive,
+            inclusive,
+            sampledata,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParameterId {
+    pub data_1: u32,
+    pub data_2: u32,
+}
+
+#[wasm_bindgen]
+impl ParameterId {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data_1: u32, data_2: u32) -> Self {
+        Self { data_1, data_2 }
+    }
+}
+
+// Strings can't be public fields for bindgen, so the name goes through the
+// constructor like the levels above.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParameterDescription {
+    #[wasm_bindgen(skip)]
+    pub name: String,
+    pub id: ParameterId,
+    pub minimum: f32,
+    pub maximum: f32,
+    pub defaultvalue: f32,
+    pub flags: u32,
+}
+
+#[wasm_bindgen]
+impl ParameterDescription {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        name: String,
+        id: ParameterId,
+        minimum: f32,
+        maximum: f32,
+        defaultvalue: f32,
+        flags: u32,
+    ) -> Self {
+        Self {
+            name,
+            id,
+            minimum,
+            maximum,
+            defaultvalue,
+            flags,
+        }
+    }
+}
+
+// GUIDs go back and forth with JS as strings like `{01234567-89ab-cdef-0123-456789abcdef}`,
+// as bindgen can't pass the array in `data_4`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Guid {
+    pub data_1: u32,
+    pub data_2: u16,
+    pub data_3: u16,
+    pub data_4: [u8; 8],
+}
+
+impl Guid {
+    fn to_js_string(self) -> String {
+        let data_4: String = self
+            .data_4
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        format!(
+            "{{{:08x}-{:04x}-{:04x}-{}-{}}}",
+            self.data_1,
+            self.data_2,
+            self.data_3,
+            &data_4[..4],
+            &data_4[4..]
+        )
+    }
+    fn from_js_string(value: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidGuid(value.to_string());
+        let hex: String = value
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .split('-')
+            .collect();
+        if hex.len() != 32 {
+            return Err(invalid());
+        }
+        let bytes = u128::from_str_radix(&hex, 16)
+            .map_err(|_| invalid())?
+            .to_be_bytes();
+
+        Ok(Guid {
+            data_1: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            data_2: u16::from_be_bytes([bytes[4], bytes[5]]),
+            data_3: u16::from_be_bytes([bytes[6], bytes[7]]),
+            data_4: [
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        })
+    }
+}
+
+// libfmod hands back the raw union alongside the type, which has no JS
+// counterpart, so the value comes across as a JsValue and is read by its type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserProperty {
+    pub name: String,
+    pub value: UserPropertyValue,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UserPropertyValue {
+    Integer(i32),
+    Boolean(bool),
+    Float(f32),
+    String(String),
+}
+
+impl UserProperty {
+    fn from_js(name: String, type_: i32, value: JsValue) -> Result<Self, Error> {
+        let mismatch = || Error::EnumBindgen {
+            enumeration: "UserPropertyType".to_string(),
+            value: type_.to_string(),
+        };
+        let value = match UserPropertyType::try_from(type_)? {
+            UserPropertyType::Integer => {
+                UserPropertyValue::Integer(value.as_f64().ok_or_else(mismatch)? as i32)
+            }
+            UserPropertyType::Boolean => {
+                UserPropertyValue::Boolean(value.as_bool().ok_or_else(mismatch)?)
+            }
+            UserPropertyType::Float => {
+                UserPropertyValue::Float(value.as_f64().ok_or_else(mismatch)? as f32)
+            }
+            UserPropertyType::String => {
+                UserPropertyValue::String(value.as_string().ok_or_else(mismatch)?)
+            }
+        };
+
+        Ok(UserProperty { name, value })
+    }
+}
+
 // Enums below are repr(i32) and explicitly annotated with numbers as source of
 // truth for those are not us.
 
@@ -541,6 +1653,27 @@ pub enum PlaybackState {
     Stopping = 4,
 }
 
+#[wasm_bindgen]
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoadingState {
+    Unloading = 0,
+    Unloaded = 1,
+    Loading = 2,
+    Loaded = 3,
+    Error = 4,
+}
+
+#[wasm_bindgen]
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UserPropertyType {
+    Integer = 0,
+    Boolean = 1,
+    Float = 2,
+    String = 3,
+}
+
 #[wasm_bindgen]
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -562,6 +1695,258 @@ pub enum StopMode {
     Immediate = 1,
 }
 
+#[wasm_bindgen]
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputType {
+    Autodetect = 0,
+    Unknown = 1,
+    NoSound = 2,
+    WavWriter = 3,
+    NoSoundNrt = 4,
+    WavWriterNrt = 5,
+    Wasapi = 6,
+    Asio = 7,
+    PulseAudio = 8,
+    Alsa = 9,
+    CoreAudio = 10,
+    AudioTrack = 11,
+    OpenSL = 12,
+    AudioOut = 13,
+    Audio3D = 14,
+    WebAudio = 15,
+    NNAudio = 16,
+    WinSonic = 17,
+    AAudio = 18,
+    AudioWorklet = 19,
+    Phase = 20,
+    Max = 21,
+}
+
+#[wasm_bindgen]
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpeakerMode {
+    Default = 0,
+    Raw = 1,
+    Mono = 2,
+    Stereo = 3,
+    Quad = 4,
+    Surround = 5,
+    Mode5Point1 = 6,
+    Mode7Point1 = 7,
+    Mode7Point1Point4 = 8,
+    Max = 9,
+}
+
+#[wasm_bindgen]
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DspType {
+    Unknown = 0,
+    Mixer = 1,
+    Oscillator = 2,
+    Lowpass = 3,
+    ItLowpass = 4,
+    Highpass = 5,
+    Echo = 6,
+    Fader = 7,
+    Flange = 8,
+    Distortion = 9,
+    Normalize = 10,
+    Limiter = 11,
+    ParamEq = 12,
+    PitchShift = 13,
+    Chorus = 14,
+    VstPlugin = 15,
+    WinampPlugin = 16,
+    ItEcho = 17,
+    Compressor = 18,
+    SfxReverb = 19,
+    LowpassSimple = 20,
+    Delay = 21,
+    Tremolo = 22,
+    LadspaPlugin = 23,
+    Send = 24,
+    Return = 25,
+    HighpassSimple = 26,
+    Pan = 27,
+    ThreeEq = 28,
+    Fft = 29,
+    LoudnessMeter = 30,
+    EnvelopeFollower = 31,
+    ConvolutionReverb = 32,
+    ChannelMix = 33,
+    Transceiver = 34,
+    ObjectPan = 35,
+    MultibandEq = 36,
+    Max = 37,
+}
+
+// Values of the enums above come back from JS as plain i32s, so these make
+// sure a mismatch between the JS shim and us is an error, not a wrong value.
+impl TryFrom<i32> for PlaybackState {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PlaybackState::Playing),
+            1 => Ok(PlaybackState::Sustaining),
+            2 => Ok(PlaybackState::Stopped),
+            3 => Ok(PlaybackState::Starting),
+            4 => Ok(PlaybackState::Stopping),
+            _ => Err(Error::EnumBindgen {
+                enumeration: "PlaybackState".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<i32> for UserPropertyType {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(UserPropertyType::Integer),
+            1 => Ok(UserPropertyType::Boolean),
+            2 => Ok(UserPropertyType::Float),
+            3 => Ok(UserPropertyType::String),
+            _ => Err(Error::EnumBindgen {
+                enumeration: "UserPropertyType".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<i32> for LoadingState {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(LoadingState::Unloading),
+            1 => Ok(LoadingState::Unloaded),
+            2 => Ok(LoadingState::Loading),
+            3 => Ok(LoadingState::Loaded),
+            4 => Ok(LoadingState::Error),
+            _ => Err(Error::EnumBindgen {
+                enumeration: "LoadingState".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<i32> for EventProperty {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(EventProperty::ChannelPriority),
+            1 => Ok(EventProperty::ScheduleDelay),
+            2 => Ok(EventProperty::ScheduleLookahead),
+            3 => Ok(EventProperty::MinimumDistance),
+            4 => Ok(EventProperty::MaximumDistance),
+            5 => Ok(EventProperty::Cooldown),
+            6 => Ok(EventProperty::Max),
+            _ => Err(Error::EnumBindgen {
+                enumeration: "EventProperty".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<i32> for StopMode {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(StopMode::AllowFadeout),
+            1 => Ok(StopMode::Immediate),
+            _ => Err(Error::EnumBindgen {
+                enumeration: "StopMode".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<i32> for DspType {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DspType::Unknown),
+            1 => Ok(DspType::Mixer),
+            2 => Ok(DspType::Oscillator),
+            3 => Ok(DspType::Lowpass),
+            4 => Ok(DspType::ItLowpass),
+            5 => Ok(DspType::Highpass),
+            6 => Ok(DspType::Echo),
+            7 => Ok(DspType::Fader),
+            8 => Ok(DspType::Flange),
+            9 => Ok(DspType::Distortion),
+            10 => Ok(DspType::Normalize),
+            11 => Ok(DspType::Limiter),
+            12 => Ok(DspType::ParamEq),
+            13 => Ok(DspType::PitchShift),
+            14 => Ok(DspType::Chorus),
+            15 => Ok(DspType::VstPlugin),
+            16 => Ok(DspType::WinampPlugin),
+            17 => Ok(DspType::ItEcho),
+            18 => Ok(DspType::Compressor),
+            19 => Ok(DspType::SfxReverb),
+            20 => Ok(DspType::LowpassSimple),
+            21 => Ok(DspType::Delay),
+            22 => Ok(DspType::Tremolo),
+            23 => Ok(DspType::LadspaPlugin),
+            24 => Ok(DspType::Send),
+            25 => Ok(DspType::Return),
+            26 => Ok(DspType::HighpassSimple),
+            27 => Ok(DspType::Pan),
+            28 => Ok(DspType::ThreeEq),
+            29 => Ok(DspType::Fft),
+            30 => Ok(DspType::LoudnessMeter),
+            31 => Ok(DspType::EnvelopeFollower),
+            32 => Ok(DspType::ConvolutionReverb),
+            33 => Ok(DspType::ChannelMix),
+            34 => Ok(DspType::Transceiver),
+            35 => Ok(DspType::ObjectPan),
+            36 => Ok(DspType::MultibandEq),
+            37 => Ok(DspType::Max),
+            _ => Err(Error::EnumBindgen {
+                enumeration: "DspType".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<i32> for SpeakerMode {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SpeakerMode::Default),
+            1 => Ok(SpeakerMode::Raw),
+            2 => Ok(SpeakerMode::Mono),
+            3 => Ok(SpeakerMode::Stereo),
+            4 => Ok(SpeakerMode::Quad),
+            5 => Ok(SpeakerMode::Surround),
+            6 => Ok(SpeakerMode::Mode5Point1),
+            7 => Ok(SpeakerMode::Mode7Point1),
+            8 => Ok(SpeakerMode::Mode7Point1Point4),
+            9 => Ok(SpeakerMode::Max),
+            _ => Err(Error::EnumBindgen {
+                enumeration: "SpeakerMode".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
 // Copy of libfmod's Error
 #[derive(Debug)]
 pub enum Error {
@@ -577,6 +1962,7 @@ pub enum Error {
     String(IntoStringError),
     StringNul(NulError),
     NotDspFft,
+    InvalidGuid(String),
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -607,6 +1993,9 @@ impl Display for Error {
             Error::NotDspFft => {
                 write!(f, "trying get FFT from DSP which not FFT")
             }
+            Error::InvalidGuid(value) => {
+                write!(f, "FMOD returns invalid GUID {}", value)
+            }
         }
     }
 }
@@ -622,6 +2011,7 @@ impl From<NulError> for Error {
 // wouldn't make a breaking change on these. This was the best I could come up
 // without making libfmod a dependency.
 bitflags! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
     pub struct Init: u32 {
         const NORMAL = 0x00000000;
         const STREAM_FROM_UPDATE = 0x00000001;
@@ -638,6 +2028,7 @@ bitflags! {
         const PROFILE_METER_ALL = 0x00200000;
         const MEMORY_TRACKING = 0x00400000;
     }
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
     pub struct LoadBank: u32 {
         const NORMAL = 0x00000000;
         const NONBLOCKING = 0x00000001;
@@ -645,6 +2036,7 @@ bitflags! {
         const UNENCRYPTED = 0x00000004;
     }
 
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
     pub struct StudioInit: u32 {
         const NORMAL = 0x00000000;
         const LIVEUPDATE = 0x00000001;
@@ -870,6 +2262,24 @@ macro_rules! create_js_result {
             }
         }
     };
+    ($type:ident, $value_0:ty, $value_1:ty, $value_2:ty) => {
+        #[wasm_bindgen]
+        #[derive(Clone, Debug)]
+        struct $type(i32, $value_0, $value_1, $value_2);
+
+        #[wasm_bindgen]
+        impl $type {
+            #[wasm_bindgen(constructor)]
+            pub fn new(
+                fmod_result: i32,
+                value_0: $value_0,
+                value_1: $value_1,
+                value_2: $value_2,
+            ) -> Self {
+                Self(fmod_result, value_0, value_1, value_2)
+            }
+        }
+    };
 }
 
 // No type, just result
@@ -890,13 +2300,26 @@ create_js_result!(JsValueVecJSResult, Vec<JsValue>);
 
 // Our custom stuff
 create_js_result!(Attributes3dJSResult, Attributes3d);
-create_js_result!(PlaybackStateJSResult, PlaybackState);
+create_js_result!(Attributes3dVectorJSResult, Attributes3d, Vector);
+create_js_result!(ParameterDescriptionJSResult, ParameterDescription);
+create_js_result!(MemoryUsageJSResult, MemoryUsage);
+create_js_result!(
+    DspMeteringInfoDspMeteringInfoJSResult,
+    DspMeteringInfo,
+    DspMeteringInfo
+);
 
 // Primitives
 create_js_result!(I32JSResult, i32);
+create_js_result!(U32JSResult, u32);
 create_js_result!(F32JSResult, f32);
 create_js_result!(BoolJSResult, bool);
 create_js_result!(StringJSResult, String);
 
 // Multiple primitive
 create_js_result!(F32F32JSResult, f32, f32);
+create_js_result!(U32I32JSResult, u32, i32);
+create_js_result!(UserPropertyJSResult, String, i32, JsValue);
+create_js_result!(I32I32JSResult, i32, i32);
+create_js_result!(F32F32F32JSResult, f32, f32, f32);
+create_js_result!(I32I32I32JSResult, i32, i32, i32);
@@ -8,9 +8,9 @@
 /// function name here is same in C and in the inner calls of libfmod FFI
 /// bindings.
 ///
-/// Functions can have three types of return, just the result, or a class of 2
-/// elements, where second element is the value, or class of 3 elements, where
-/// last two elements are values.
+/// Functions can have four types of return, just the result, or a class of 2
+/// elements, where second element is the value, or class of 3 or 4 elements,
+/// where all elements but the first are values.
 ///
 /// Then, the Rust side of this code checks whether result is OK or not and
 /// converts it into Result<T, E>. I made this choice because as far as I can
@@ -74,6 +74,10 @@ const {
   // Structs
   Vector,
   Attributes3d,
+  DspMeteringInfo,
+  MemoryUsage,
+  ParameterId,
+  ParameterDescription,
   // Typeless results
   JSResult,
   JsValueJSResult,
@@ -81,21 +85,62 @@ const {
   
   // Typed results
   Attributes3dJSResult,
-  PlaybackStateJSResult,
+  Attributes3dVectorJSResult,
+  ParameterDescriptionJSResult,
+  MemoryUsageJSResult,
+  DspMeteringInfoDspMeteringInfoJSResult,
   
   // Typed primitive results
   I32JSResult,
+  U32JSResult,
   F32JSResult,
   BoolJSResult,
   StringJSResult,
   
   // Typed tuple primitive results
   F32F32JSResult,
+  U32I32JSResult,
+  I32I32JSResult,
+  UserPropertyJSResult,
+  F32F32F32JSResult,
+  I32I32I32JSResult,
 } = wasm_bindgen;
 
 
 // Below are the bindings that wasm-bindgen calls.
 
+// Common
+
+function FMOD_ErrorString(result) {
+  return FMOD.ErrorString(result);
+}
+function Memory_GetStats(blocking) {
+  const currentAlloced = {};
+  const maxAlloced = {};
+  const result = FMOD.Memory_GetStats(currentAlloced, maxAlloced, blocking);
+  return new I32I32JSResult(result, currentAlloced.val, maxAlloced.val);
+}
+
+// GUIDs are passed to Rust as strings, since bindgen can't take the Data4 array
+function guidToString(guid) {
+  const hex = (value, digits) => (value >>> 0).toString(16).padStart(digits, "0");
+  const data4 = Array.from(guid.Data4, (byte) => hex(byte, 2)).join("");
+  return `{${hex(guid.Data1, 8)}-${hex(guid.Data2, 4)}-${hex(guid.Data3, 4)}-${data4.slice(0, 4)}-${data4.slice(4)}}`;
+}
+function guidFromString(value) {
+  const hex = value.replace(/[{}-]/g, "");
+  const data4 = [];
+  for (let i = 16; i < 32; i += 2) {
+    data4.push(parseInt(hex.slice(i, i + 2), 16));
+  }
+  return {
+    Data1: parseInt(hex.slice(0, 8), 16),
+    Data2: parseInt(hex.slice(8, 12), 16),
+    Data3: parseInt(hex.slice(12, 16), 16),
+    Data4: data4,
+  };
+}
+
 // Studio
 
 function Studio_System_Create() {
@@ -118,12 +163,18 @@ function Studio_System_Initialize(
   );
   return new JSResult(result);
 }
-function Studio_System_LoadBankMemory(studio, buffer, mode, flags) {
+function Studio_System_Release(studio) {
+  const result = studio.release();
+  return new JSResult(result);
+}
+function Studio_System_LoadBankMemory(studio, buffer, flags) {
   const bank = {};
+  // 0 is FMOD_STUDIO_LOAD_MEMORY, which copies the buffer. Rust side doesn't
+  // keep the buffer alive, so point mode can't be used.
   const result = studio.loadBankMemory(
     buffer,
     buffer.length,
-    mode,
+    0,
     flags,
     bank,
   );
@@ -138,6 +189,11 @@ function Studio_System_GetEvent(studio, path) {
   const result = studio.getEvent(path, event);
   return new JsValueJSResult(result, event.val);
 }
+function Studio_System_GetEventByID(studio, id) {
+  const event = {};
+  const result = studio.getEventByID(guidFromString(id), event);
+  return new JsValueJSResult(result, event.val);
+}
 function Studio_System_GetBus(studio, path) {
   const bus = {};
   const result = studio.getBus(path, bus);
@@ -152,6 +208,37 @@ function Studio_System_SetParameterByName(
   const result = studio.setParameterByName(name, value, ignoreSeekSpeed);
   return new JSResult(result);
 }
+function Studio_System_SetParameterByID(studio, id, value, ignoreSeekSpeed) {
+  const result = studio.setParameterByID(
+    { data1: id.data_1, data2: id.data_2 },
+    value,
+    ignoreSeekSpeed
+  );
+  return new JSResult(result);
+}
+function Studio_System_GetParameterDescriptionByName(studio, name) {
+  const parameter = {};
+  const result = studio.getParameterDescriptionByName(name, parameter);
+  return new ParameterDescriptionJSResult(result, toParameterDescription(parameter.val));
+}
+function Studio_System_SetNumListeners(studio, numListeners) {
+  const result = studio.setNumListeners(numListeners);
+  return new JSResult(result);
+}
+function Studio_System_GetNumListeners(studio) {
+  const numListeners = {};
+  const result = studio.getNumListeners(numListeners);
+  return new I32JSResult(result, numListeners.val);
+}
+function Studio_System_SetListenerWeight(studio, listener, weight) {
+  const result = studio.setListenerWeight(listener, weight);
+  return new JSResult(result);
+}
+function Studio_System_GetListenerWeight(studio, listener) {
+  const weight = {};
+  const result = studio.getListenerWeight(listener, weight);
+  return new F32JSResult(result, weight.val);
+}
 function Studio_System_SetListenerAttributes(
   studio,
   listener,
@@ -165,10 +252,137 @@ function Studio_System_SetListenerAttributes(
   );
   return new JSResult(result);
 }
+function Studio_System_GetListenerAttributes(studio, listener) {
+  const attributes = {};
+  const attenuationPosition = {};
+  const result = studio.getListenerAttributes(
+    listener,
+    attributes,
+    attenuationPosition,
+  );
+  // Same flattened fields as Studio_EventInstance_Get3DAttributes
+  return new Attributes3dVectorJSResult(
+    result,
+    new Attributes3d(
+      new Vector(attributes["position.x"], attributes["position.y"], attributes["position.z"]),
+      new Vector(attributes["velocity.x"], attributes["velocity.y"], attributes["velocity.z"]),
+      new Vector(attributes["forward.x"], attributes["forward.y"], attributes["forward.z"]),
+      new Vector(attributes["up.x"], attributes["up.y"], attributes["up.z"]),
+    ),
+    new Vector(attenuationPosition.x, attenuationPosition.y, attenuationPosition.z),
+  );
+}
 function Studio_System_Update(studio) {
   const result = studio.update();
   return new JSResult(result);
 }
+function Studio_System_FlushCommands(studio) {
+  const result = studio.flushCommands();
+  return new JSResult(result);
+}
+function Studio_System_FlushSampleLoading(studio) {
+  const result = studio.flushSampleLoading();
+  return new JSResult(result);
+}
+function Studio_System_GetCoreSystem(studio) {
+  const system = {};
+  const result = studio.getCoreSystem(system);
+  return new JsValueJSResult(result, system.val);
+}
+
+// System
+
+function System_SetOutput(system, output) {
+  const result = system.setOutput(output);
+  return new JSResult(result);
+}
+function System_SetSoftwareFormat(system, sampleRate, speakerMode, numRawSpeakers) {
+  const result = system.setSoftwareFormat(sampleRate, speakerMode, numRawSpeakers);
+  return new JSResult(result);
+}
+function System_GetSoftwareFormat(system) {
+  const sampleRate = {};
+  const speakerMode = {};
+  const numRawSpeakers = {};
+  const result = system.getSoftwareFormat(sampleRate, speakerMode, numRawSpeakers);
+  return new I32I32I32JSResult(result, sampleRate.val, speakerMode.val, numRawSpeakers.val);
+}
+function System_SetDSPBufferSize(system, bufferLength, numBuffers) {
+  const result = system.setDSPBufferSize(bufferLength, numBuffers);
+  return new JSResult(result);
+}
+function System_GetDSPBufferSize(system) {
+  const bufferLength = {};
+  const numBuffers = {};
+  const result = system.getDSPBufferSize(bufferLength, numBuffers);
+  return new U32I32JSResult(result, bufferLength.val, numBuffers.val);
+}
+function System_MixerSuspend(system) {
+  const result = system.mixerSuspend();
+  return new JSResult(result);
+}
+function System_MixerResume(system) {
+  const result = system.mixerResume();
+  return new JSResult(result);
+}
+function System_Set3DSettings(system, dopplerScale, distanceFactor, rolloffScale) {
+  const result = system.set3DSettings(dopplerScale, distanceFactor, rolloffScale);
+  return new JSResult(result);
+}
+function System_Get3DSettings(system) {
+  const dopplerScale = {};
+  const distanceFactor = {};
+  const rolloffScale = {};
+  const result = system.get3DSettings(dopplerScale, distanceFactor, rolloffScale);
+  return new F32F32F32JSResult(result, dopplerScale.val, distanceFactor.val, rolloffScale.val);
+}
+function System_GetMasterChannelGroup(system) {
+  const channelGroup = {};
+  const result = system.getMasterChannelGroup(channelGroup);
+  return new JsValueJSResult(result, channelGroup.val);
+}
+function System_CreateDSPByType(system, dspType) {
+  const dsp = {};
+  const result = system.createDSPByType(dspType, dsp);
+  return new JsValueJSResult(result, dsp.val);
+}
+function System_CreateSound(system, data, mode) {
+  const sound = {};
+  // Rust side always passes FMOD_OPENMEMORY, so FMOD copies the data and
+  // needs its length.
+  const exinfo = FMOD.CREATESOUNDEXINFO();
+  exinfo.length = data.length;
+  const result = system.createSound(data, mode, exinfo, sound);
+  return new JsValueJSResult(result, sound.val);
+}
+function System_PlaySound(system, sound, channelGroup, paused) {
+  const channel = {};
+  const result = system.playSound(sound, channelGroup, paused, channel);
+  return new JsValueJSResult(result, channel.val);
+}
+function System_SetReverbProperties(system, instance, prop) {
+  // FMOD reads the fields by their C names
+  const result = system.setReverbProperties(instance, {
+    DecayTime: prop.decay_time,
+    EarlyDelay: prop.early_delay,
+    LateDelay: prop.late_delay,
+    HFReference: prop.hf_reference,
+    HFDecayRatio: prop.hf_decay_ratio,
+    Diffusion: prop.diffusion,
+    Density: prop.density,
+    LowShelfFrequency: prop.low_shelf_frequency,
+    LowShelfGain: prop.low_shelf_gain,
+    HighCut: prop.high_cut,
+    EarlyLateMix: prop.early_late_mix,
+    WetLevel: prop.wet_level,
+  });
+  return new JSResult(result);
+}
+function System_GetVersion(system) {
+  const version = {};
+  const result = system.getVersion(version);
+  return new U32JSResult(result, version.val);
+}
 
 // Bank
 
@@ -183,9 +397,54 @@ function Studio_Bank_GetEventCount(bank) {
   const result = bank.getEventCount(count);
   return new I32JSResult(result, count.val);
 }
+function Studio_Bank_GetLoadingState(bank) {
+  const state = {};
+  const result = bank.getLoadingState(state);
+  return new I32JSResult(result, state.val);
+}
+function Studio_Bank_GetSampleLoadingState(bank) {
+  const state = {};
+  const result = bank.getSampleLoadingState(state);
+  return new I32JSResult(result, state.val);
+}
+function Studio_Bank_GetID(bank) {
+  const id = {};
+  const result = bank.getID(id);
+  // 0 is OK
+  if (result !== 0) {
+    return new StringJSResult(result, "");
+  }
+  return new StringJSResult(result, guidToString(id.val));
+}
+function Studio_Bank_IsValid(bank) {
+  return bank.isValid();
+}
+function Studio_Bank_GetPath(bank) {
+  const retrieved = {};
+  let result = bank.getPath(null, 0, retrieved);
+  // 0 is OK
+  if (result !== 0) {
+    return new StringJSResult(result, null);
+  }
+  const path = {};
+  result = bank.getPath(path, retrieved.val, retrieved);
+  return new StringJSResult(result, path.val);
+}
 
 // EventDescription
 
+// Bindgen classes can't be null, so a failed call still gets an (empty) description
+function toParameterDescription(description = { id: {} }) {
+  return new ParameterDescription(
+    description.name ?? "",
+    new ParameterId(description.id.data1 ?? 0, description.id.data2 ?? 0),
+    description.minimum ?? 0,
+    description.maximum ?? 0,
+    description.defaultvalue ?? 0,
+    description.flags ?? 0,
+  );
+}
+
 function Studio_EventDescription_GetPath(eventDescription) {
   const retrieved = {};
   let result = eventDescription.getPath(null, 0, retrieved);
@@ -207,6 +466,81 @@ function Studio_EventDescription_GetInstanceCount(eventDescription) {
   const result = eventDescription.getInstanceCount(count);
   return new I32JSResult(result, count.val);
 }
+function Studio_EventDescription_LoadSampleData(eventDescription) {
+  const result = eventDescription.loadSampleData();
+  return new JSResult(result);
+}
+function Studio_EventDescription_UnloadSampleData(eventDescription) {
+  const result = eventDescription.unloadSampleData();
+  return new JSResult(result);
+}
+function Studio_EventDescription_GetSampleLoadingState(eventDescription) {
+  const state = {};
+  const result = eventDescription.getSampleLoadingState(state);
+  return new I32JSResult(result, state.val);
+}
+function Studio_EventDescription_GetInstanceList(eventDescription, capacity) {
+  const array = {};
+  const count = {};
+  const result = eventDescription.getInstanceList(array, capacity, count);
+  return new JsValueVecJSResult(result, array.val.slice(0, count.val));
+}
+function Studio_EventDescription_Is3D(eventDescription) {
+  const is3D = {};
+  const result = eventDescription.is3D(is3D);
+  return new BoolJSResult(result, is3D.val);
+}
+function Studio_EventDescription_IsValid(eventDescription) {
+  return eventDescription.isValid();
+}
+function Studio_EventDescription_IsOneshot(eventDescription) {
+  const oneshot = {};
+  const result = eventDescription.isOneshot(oneshot);
+  return new BoolJSResult(result, oneshot.val);
+}
+function Studio_EventDescription_GetMinMaxDistance(eventDescription) {
+  const min = {};
+  const max = {};
+  const result = eventDescription.getMinMaxDistance(min, max);
+  return new F32F32JSResult(result, min.val, max.val);
+}
+function Studio_EventDescription_GetParameterDescriptionCount(eventDescription) {
+  const count = {};
+  const result = eventDescription.getParameterDescriptionCount(count);
+  return new I32JSResult(result, count.val);
+}
+function Studio_EventDescription_GetParameterDescriptionByIndex(eventDescription, index) {
+  const parameter = {};
+  const result = eventDescription.getParameterDescriptionByIndex(index, parameter);
+  return new ParameterDescriptionJSResult(result, toParameterDescription(parameter.val));
+}
+
+// The C union becomes one value, picked by the type of the property
+function toUserPropertyJSResult(result, property = {}) {
+  const type = property.type ?? 0;
+  const value = [
+    property.intvalue ?? 0,
+    property.boolvalue ?? false,
+    property.floatvalue ?? 0,
+    property.stringvalue ?? "",
+  ][type];
+  return new UserPropertyJSResult(result, property.name ?? "", type, value);
+}
+function Studio_EventDescription_GetUserProperty(eventDescription, name) {
+  const property = {};
+  const result = eventDescription.getUserProperty(name, property);
+  return toUserPropertyJSResult(result, property.val);
+}
+function Studio_EventDescription_GetUserPropertyCount(eventDescription) {
+  const count = {};
+  const result = eventDescription.getUserPropertyCount(count);
+  return new I32JSResult(result, count.val);
+}
+function Studio_EventDescription_GetUserPropertyByIndex(eventDescription, index) {
+  const property = {};
+  const result = eventDescription.getUserPropertyByIndex(index, property);
+  return toUserPropertyJSResult(result, property.val);
+}
 
 // EventInstance
 
@@ -278,6 +612,16 @@ function Studio_EventInstance_IsVirtual(eventInstance) {
   const result = eventInstance.isVirtual(virtual);
   return new BoolJSResult(result, virtual.val);
 }
+function Studio_EventInstance_GetMemoryUsage(eventInstance) {
+  const memoryUsage = {};
+  const result = eventInstance.getMemoryUsage(memoryUsage);
+  // Struct outs are filled in place, but be lenient in case it's under val
+  const usage = memoryUsage.val ?? memoryUsage;
+  return new MemoryUsageJSResult(
+    result,
+    new MemoryUsage(usage.exclusive ?? 0, usage.inclusive ?? 0, usage.sampledata ?? 0),
+  );
+}
 function Studio_EventInstance_GetParameterByName(eventInstance, name) {
   const value = {};
   const finalValue = {};
@@ -313,12 +657,185 @@ function Studio_EventInstance_GetPaused(eventInstance) {
 function Studio_EventInstance_GetPlaybackState(eventInstance) {
   const state = {};
   const result = eventInstance.getPlaybackState(state);
-  return new PlaybackStateJSResult(result, state.val);
+  return new I32JSResult(result, state.val);
+}
+function Studio_EventInstance_IsValid(eventInstance) {
+  return eventInstance.isValid();
+}
+function Studio_EventInstance_GetDescription(eventInstance) {
+  const description = {};
+  const result = eventInstance.getDescription(description);
+  return new JsValueJSResult(result, description.val);
+}
+function Studio_EventInstance_SetUserData(eventInstance, userdata) {
+  const result = eventInstance.setUserData(userdata);
+  return new JSResult(result);
+}
+function Studio_EventInstance_GetUserData(eventInstance) {
+  const userdata = {};
+  const result = eventInstance.getUserData(userdata);
+  return new U32JSResult(result, userdata.val);
+}
+function Studio_EventInstance_KeyOff(eventInstance) {
+  const result = eventInstance.keyOff();
+  return new JSResult(result);
+}
+function Studio_EventInstance_SetListenerMask(eventInstance, mask) {
+  const result = eventInstance.setListenerMask(mask);
+  return new JSResult(result);
+}
+function Studio_EventInstance_GetListenerMask(eventInstance) {
+  const mask = {};
+  const result = eventInstance.getListenerMask(mask);
+  return new U32JSResult(result, mask.val);
+}
+function Studio_EventInstance_GetChannelGroup(eventInstance) {
+  const channelGroup = {};
+  const result = eventInstance.getChannelGroup(channelGroup);
+  return new JsValueJSResult(result, channelGroup.val);
 }
 
 // Bus
 
+function Studio_Bus_StopAllEvents(bus, mode) {
+  const result = bus.stopAllEvents(mode);
+  return new JSResult(result);
+}
+function Studio_Bus_LockChannelGroup(bus) {
+  const result = bus.lockChannelGroup();
+  return new JSResult(result);
+}
+function Studio_Bus_GetChannelGroup(bus) {
+  const channelGroup = {};
+  const result = bus.getChannelGroup(channelGroup);
+  return new JsValueJSResult(result, channelGroup.val);
+}
+function Studio_Bus_GetMute(bus) {
+  const mute = {};
+  const result = bus.getMute(mute);
+  return new BoolJSResult(result, mute.val);
+}
 function Studio_Bus_SetMute(bus, mute) {
   const result = bus.setMute(mute);
   return new JSResult(result);
 }
+function Studio_Bus_GetVolume(bus) {
+  const volume = {};
+  const finalVolume = {};
+  const result = bus.getVolume(volume, finalVolume);
+  return new F32F32JSResult(result, volume.val, finalVolume.val);
+}
+function Studio_Bus_SetVolume(bus, volume) {
+  const result = bus.setVolume(volume);
+  return new JSResult(result);
+}
+function Studio_Bus_GetPaused(bus) {
+  const paused = {};
+  const result = bus.getPaused(paused);
+  return new BoolJSResult(result, paused.val);
+}
+function Studio_Bus_SetPaused(bus, paused) {
+  const result = bus.setPaused(paused);
+  return new JSResult(result);
+}
+
+// ChannelGroup
+
+function ChannelGroup_GetDSP(channelGroup, index) {
+  const dsp = {};
+  const result = channelGroup.getDSP(index, dsp);
+  return new JsValueJSResult(result, dsp.val);
+}
+function ChannelGroup_AddDSP(channelGroup, index, dsp) {
+  const result = channelGroup.addDSP(index, dsp);
+  return new JSResult(result);
+}
+function ChannelGroup_RemoveDSP(channelGroup, dsp) {
+  const result = channelGroup.removeDSP(dsp);
+  return new JSResult(result);
+}
+function ChannelGroup_GetSystemObject(channelGroup) {
+  const system = {};
+  const result = channelGroup.getSystemObject(system);
+  return new JsValueJSResult(result, system.val);
+}
+function ChannelGroup_Set3DConeSettings(channelGroup, insideConeAngle, outsideConeAngle, outsideVolume) {
+  const result = channelGroup.set3DConeSettings(insideConeAngle, outsideConeAngle, outsideVolume);
+  return new JSResult(result);
+}
+function ChannelGroup_Set3DOcclusion(channelGroup, directOcclusion, reverbOcclusion) {
+  const result = channelGroup.set3DOcclusion(directOcclusion, reverbOcclusion);
+  return new JSResult(result);
+}
+
+// DSP
+
+function DSP_SetMeteringEnabled(dsp, inputEnabled, outputEnabled) {
+  const result = dsp.setMeteringEnabled(inputEnabled, outputEnabled);
+  return new JSResult(result);
+}
+function DSP_GetType(dsp) {
+  const dspType = {};
+  const result = dsp.getType(dspType);
+  return new I32JSResult(result, dspType.val);
+}
+function DSP_SetParameterFloat(dsp, index, value) {
+  const result = dsp.setParameterFloat(index, value);
+  return new JSResult(result);
+}
+function DSP_Release(dsp) {
+  const result = dsp.release();
+  return new JSResult(result);
+}
+function DSP_SetUserData(dsp, userdata) {
+  const result = dsp.setUserData(userdata);
+  return new JSResult(result);
+}
+function DSP_GetUserData(dsp) {
+  const userdata = {};
+  const result = dsp.getUserData(userdata);
+  return new U32JSResult(result, userdata.val);
+}
+function DSP_GetMeteringInfo(dsp) {
+  const inputInfo = {};
+  const outputInfo = {};
+  const result = dsp.getMeteringInfo(inputInfo, outputInfo);
+  return new DspMeteringInfoDspMeteringInfoJSResult(
+    result,
+    new DspMeteringInfo(
+      inputInfo.numsamples,
+      inputInfo.peaklevel,
+      inputInfo.rmslevel,
+      inputInfo.numchannels,
+    ),
+    new DspMeteringInfo(
+      outputInfo.numsamples,
+      outputInfo.peaklevel,
+      outputInfo.rmslevel,
+      outputInfo.numchannels,
+    ),
+  );
+}
+
+// Sound
+
+function Sound_Release(sound) {
+  const result = sound.release();
+  return new JSResult(result);
+}
+
+// Channel
+
+function Channel_SetVolume(channel, volume) {
+  const result = channel.setVolume(volume);
+  return new JSResult(result);
+}
+function Channel_Stop(channel) {
+  const result = channel.stop();
+  return new JSResult(result);
+}
+function Channel_IsPlaying(channel) {
+  const isPlaying = {};
+  const result = channel.isPlaying(isPlaying);
+  return new BoolJSResult(result, isPlaying.val);
+}